@@ -1,9 +1,25 @@
-use crate::config::app_config::SourcesConfig;
-use crate::config::frontend::{transform_platform_source, FrontendAppConfig, FrontendPlatformSource};
-use crate::platform_ops::{parse_platform, try_convert_with_all_platforms, with_platform_data};
+use crate::config::app_config::{
+    ClearUrlsSettings, ClipboardSettings, FediverseSettings, NormalizeSettings, PrivacyFrontendSettings, QueryPolicy,
+    SourcesConfig,
+};
+use crate::config::frontend::{
+    transform_platform_source, ConfigWarning, FrontendAppConfig, FrontendClearUrlsConfig, FrontendNormalizeConfig,
+    FrontendPlatformSource, FrontendPrivacyConfig,
+};
+use crate::platform_ops::{
+    apply_custom_frontends, apply_fediverse_settings, apply_normalize_settings, apply_privacy_settings,
+    convert_clipboard_content, explain_conversion_with_all_platforms, parse_platform,
+    resolve_converter, try_canonicalize_with_all_platforms, try_convert_text_with_all_platforms, validate_sources,
+    with_platform_data,
+};
 use crate::services::clipboard::{ClipboardManager, SystemClipboard};
+use crate::services::converters::conversion::{Conversion, ConversionError};
+use crate::services::converters::custom::CustomFrontend;
+use crate::services::converters::options::ConverterOptions;
+use crate::services::converters::LinkConverterStrategy;
 use crate::services::link_converter::LinkConverter;
-use crate::state::StateManager;
+use crate::state::{ConfigWarnings, StateManager};
+use crate::tray_menu::menu::refresh_tray_menu;
 use once_cell::sync::Lazy;
 use std::sync::Mutex;
 use tauri::{AppHandle, Emitter, Manager};
@@ -11,22 +27,98 @@ use tauri::{AppHandle, Emitter, Manager};
 
 static CLIPBOARD_MANAGER: Lazy<Mutex<ClipboardManager<SystemClipboard>>> =
     Lazy::new(|| Mutex::new(ClipboardManager::new()));
-static LINK_CONVERTER: Lazy<LinkConverter> = Lazy::new(|| LinkConverter::new());
 
-// Helper function to emit config updates to frontend
-fn emit_config_update(app: &AppHandle, state_manager: &tauri::State<StateManager>) {
-    let config = state_manager.get_state();
+// Helper function to build the frontend view of the config
+fn build_frontend_config(config: &SourcesConfig, link_converter: &LinkConverter) -> FrontendAppConfig {
     let frontend_sources: Vec<FrontendPlatformSource> = config
         .sources
         .iter()
-        .map(|source| transform_platform_source(source))
+        .map(|source| {
+            let registry = link_converter.registry();
+            let platform = source.get_platform_name();
+            let available = registry.available_converters(platform);
+            let active = resolve_converter(source.get_operations(), link_converter, platform).ok();
+            let passthrough = active
+                .as_ref()
+                .is_some_and(|active| registry.is_passthrough(platform, active));
+            let supported_options = active
+                .map(|active| registry.supported_options(platform, &active))
+                .unwrap_or_default();
+            transform_platform_source(source, &available, active.as_deref(), passthrough, &supported_options)
+        })
         .collect();
-    
-    let frontend_config = FrontendAppConfig {
+
+    FrontendAppConfig {
         sources: frontend_sources,
-    };
+        clipboard: config.clipboard.clone(),
+        clear_urls: FrontendClearUrlsConfig {
+            settings: config.clear_urls.clone(),
+            providers: link_converter.clear_urls().len(),
+        },
+        fediverse: config.fediverse.clone(),
+        normalize: FrontendNormalizeConfig {
+            settings: config.normalize.clone(),
+            rules: link_converter
+                .registry()
+                .normalizer()
+                .rule_names()
+                .into_iter()
+                .map(String::from)
+                .collect(),
+        },
+        privacy: link_converter
+            .registry()
+            .privacy_frontends()
+            .iter()
+            .map(|frontend| {
+                let platform = frontend.platform_name().to_string();
+                let settings = config.privacy.get(&platform).cloned().unwrap_or_default();
+                (platform, FrontendPrivacyConfig {
+                    settings,
+                    frontend: frontend.name().to_string(),
+                })
+            })
+            .collect(),
+    }
+}
+
+// Helper function to emit config updates to frontend
+fn emit_config_update(app: &AppHandle, state_manager: &StateManager) {
+    let config = state_manager.get_state();
+    let link_converter = app.state::<LinkConverter>();
+    let frontend_config = build_frontend_config(&config, &link_converter);
     
     let _ = app.emit("config-updated", &frontend_config);
+    refresh_tray_menu(app);
+}
+
+/// Mark a converter as unavailable, or restore it, so conversions skip to the next preferred one.
+/// Shared by the command and the tray menu; frontends are not probed, so only the user marks them.
+pub fn set_converter_availability(
+    state_manager: &StateManager,
+    link_converter: &LinkConverter,
+    platform: &str,
+    converter: &str,
+    available: bool,
+) -> Result<(), String> {
+    let platform_enum = parse_platform(platform)
+        .ok_or_else(|| format!("Unknown platform: {}", platform))?;
+    let is_registered = link_converter
+        .registry()
+        .available_converters(&platform.to_lowercase())
+        .iter()
+        .any(|name| name == converter);
+    if !is_registered {
+        return Err(format!("Converter '{}' is not available for {}", converter, platform));
+    }
+
+    state_manager.update_state(|state| {
+        with_platform_data(state, platform_enum, |data| data.set_converter_available(converter, available));
+    });
+
+    state_manager.save_to_file();
+    emit_config_update(&state_manager.app, state_manager);
+    Ok(())
 }
 
 #[tauri::command]
@@ -48,18 +140,17 @@ pub fn get_config() -> SourcesConfig {
 }
 
 #[tauri::command]
-pub fn get_frontend_config(state_manager: tauri::State<StateManager>) -> FrontendAppConfig {
+pub fn get_frontend_config(
+    state_manager: tauri::State<StateManager>,
+    link_converter: tauri::State<LinkConverter>,
+) -> FrontendAppConfig {
     let config = state_manager.get_state();
-    
-    let frontend_sources: Vec<FrontendPlatformSource> = config
-        .sources
-        .iter()
-        .map(|source| transform_platform_source(source))
-        .collect();
-    
-    FrontendAppConfig {
-        sources: frontend_sources,
-    }
+    build_frontend_config(&config, &link_converter)
+}
+
+#[tauri::command]
+pub fn get_config_warnings(warnings: tauri::State<ConfigWarnings>) -> Vec<ConfigWarning> {
+    warnings.0.lock().unwrap().clone()
 }
 
 #[tauri::command]
@@ -99,15 +190,23 @@ pub fn select_converter(
     platform: String,
     converter_name: String,
     state_manager: tauri::State<StateManager>,
+    link_converter: tauri::State<LinkConverter>,
 ) -> Result<(), String> {
     println!("select_converter called with platform: {}, converter_name: {}", platform, converter_name);
 
     let platform_enum = parse_platform(&platform)
         .ok_or_else(|| format!("Unknown platform: {}", platform))?;
 
+    // Converters from the user's definitions file are not part of the configured list
+    let is_registered = link_converter
+        .registry()
+        .available_converters(&platform.to_lowercase())
+        .contains(&converter_name);
+
     let success = state_manager.update_state(|state| {
         with_platform_data(state, platform_enum, |data| {
             data.set_converter_by_name(&converter_name)
+                || (is_registered && data.set_custom_converter(&converter_name))
         }).unwrap_or(false)
     });
 
@@ -120,6 +219,214 @@ pub fn select_converter(
     Ok(())
 }
 
+#[tauri::command]
+pub fn set_preferred_converters(
+    app: AppHandle,
+    platform: String,
+    converters: Vec<String>,
+    state_manager: tauri::State<StateManager>,
+    link_converter: tauri::State<LinkConverter>,
+) -> Result<(), String> {
+    let platform_enum = parse_platform(&platform)
+        .ok_or_else(|| format!("Unknown platform: {}", platform))?;
+
+    let available = link_converter.registry().available_converters(&platform.to_lowercase());
+    if let Some(unknown) = converters.iter().find(|name| !available.contains(*name)) {
+        return Err(format!("Converter '{}' is not available for {}", unknown, platform));
+    }
+
+    state_manager.update_state(|state| {
+        with_platform_data(state, platform_enum, |data| data.set_preferred_converters(&converters));
+    });
+
+    state_manager.save_to_file();
+    emit_config_update(&app, &state_manager);
+    Ok(())
+}
+
+#[tauri::command]
+pub fn set_converter_available(
+    platform: String,
+    converter: String,
+    available: bool,
+    state_manager: tauri::State<StateManager>,
+    link_converter: tauri::State<LinkConverter>,
+) -> Result<(), String> {
+    set_converter_availability(&state_manager, &link_converter, &platform, &converter, available)
+}
+
+#[tauri::command]
+pub fn update_fediverse_settings(
+    app: AppHandle,
+    settings: FediverseSettings,
+    state_manager: tauri::State<StateManager>,
+    link_converter: tauri::State<LinkConverter>,
+) -> Result<(), String> {
+    apply_fediverse_settings(&settings, &link_converter)?;
+    state_manager.update_state(|state| {
+        state.fediverse = settings;
+    });
+
+    state_manager.save_to_file();
+    emit_config_update(&app, &state_manager);
+    Ok(())
+}
+
+#[tauri::command]
+pub fn update_normalize_settings(
+    app: AppHandle,
+    settings: NormalizeSettings,
+    state_manager: tauri::State<StateManager>,
+    link_converter: tauri::State<LinkConverter>,
+) -> Result<(), String> {
+    apply_normalize_settings(&settings, &link_converter);
+    state_manager.update_state(|state| {
+        state.normalize = settings;
+    });
+
+    state_manager.save_to_file();
+    emit_config_update(&app, &state_manager);
+    Ok(())
+}
+
+#[tauri::command]
+pub fn update_privacy_settings(
+    app: AppHandle,
+    platform: String,
+    settings: PrivacyFrontendSettings,
+    state_manager: tauri::State<StateManager>,
+    link_converter: tauri::State<LinkConverter>,
+) -> Result<(), String> {
+    let platform = platform.to_lowercase();
+    let has_frontend = link_converter
+        .registry()
+        .privacy_frontends()
+        .iter()
+        .any(|frontend| frontend.platform_name() == platform);
+    if !has_frontend {
+        return Err(format!("No privacy frontend for platform: {}", platform));
+    }
+
+    let privacy = state_manager.update_state(|state| {
+        state.privacy.insert(platform, settings);
+        state.privacy.clone()
+    });
+    apply_privacy_settings(&privacy, &link_converter);
+
+    state_manager.save_to_file();
+    emit_config_update(&app, &state_manager);
+    Ok(())
+}
+
+#[tauri::command]
+pub fn update_custom_converters(
+    app: AppHandle,
+    platform: String,
+    converters: Vec<CustomFrontend>,
+    state_manager: tauri::State<StateManager>,
+    link_converter: tauri::State<LinkConverter>,
+    config_warnings: tauri::State<ConfigWarnings>,
+) -> Result<(), String> {
+    let platform_enum = parse_platform(&platform)
+        .ok_or_else(|| format!("Unknown platform: {}", platform))?;
+    let platform = platform.to_lowercase();
+
+    // Reject the whole list if any entry is invalid, putting the saved frontends back
+    let errors = link_converter
+        .registry()
+        .configure_custom_frontends(&platform, &converters)
+        .ok_or_else(|| format!("Custom converters are not supported for {}", platform))?;
+    if !errors.is_empty() {
+        apply_custom_frontends(&state_manager.get_state(), &link_converter);
+        return Err(errors.join("\n"));
+    }
+
+    state_manager.update_state(|state| {
+        with_platform_data(state, platform_enum, |data| data.set_custom_frontends(converters));
+    });
+
+    // A removed frontend may have been a preferred converter
+    let warnings = validate_sources(&state_manager.get_state(), &link_converter);
+    *config_warnings.0.lock().unwrap() = warnings.clone();
+    let _ = app.emit("config-warnings", &warnings);
+
+    state_manager.save_to_file();
+    emit_config_update(&app, &state_manager);
+    Ok(())
+}
+
+#[tauri::command]
+pub fn set_query_policy(
+    app: AppHandle,
+    platform: String,
+    policy: QueryPolicy,
+    state_manager: tauri::State<StateManager>,
+) -> Result<(), String> {
+    let platform_enum = parse_platform(&platform)
+        .ok_or_else(|| format!("Unknown platform: {}", platform))?;
+
+    state_manager.update_state(|state| {
+        with_platform_data(state, platform_enum, |data| {
+            data.set_query_policy(policy);
+        });
+    });
+
+    state_manager.save_to_file();
+    emit_config_update(&app, &state_manager);
+    Ok(())
+}
+
+#[tauri::command]
+pub fn set_converter_options(
+    app: AppHandle,
+    platform: String,
+    options: ConverterOptions,
+    state_manager: tauri::State<StateManager>,
+) -> Result<(), String> {
+    let platform_enum = parse_platform(&platform)
+        .ok_or_else(|| format!("Unknown platform: {}", platform))?;
+
+    state_manager.update_state(|state| {
+        with_platform_data(state, platform_enum, |data| {
+            data.set_options(options);
+        });
+    });
+
+    state_manager.save_to_file();
+    emit_config_update(&app, &state_manager);
+    Ok(())
+}
+
+#[tauri::command]
+pub fn update_clipboard_settings(
+    app: AppHandle,
+    settings: ClipboardSettings,
+    state_manager: tauri::State<StateManager>,
+) -> Result<(), String> {
+    state_manager.update_state(|state| {
+        state.clipboard = settings;
+    });
+
+    state_manager.save_to_file();
+    emit_config_update(&app, &state_manager);
+    Ok(())
+}
+
+#[tauri::command]
+pub fn update_clear_urls_settings(
+    app: AppHandle,
+    settings: ClearUrlsSettings,
+    state_manager: tauri::State<StateManager>,
+) -> Result<(), String> {
+    state_manager.update_state(|state| {
+        state.clear_urls = settings;
+    });
+
+    state_manager.save_to_file();
+    emit_config_update(&app, &state_manager);
+    Ok(())
+}
+
 #[tauri::command]
 pub fn update_state(
     app: AppHandle,
@@ -145,11 +452,38 @@ pub fn update_state(
 }
 
 #[tauri::command]
-pub fn convert_link(url: String, state_manager: tauri::State<StateManager>) -> Result<String, String> {
+pub fn convert_link(
+    url: String,
+    state_manager: tauri::State<StateManager>,
+    link_converter: tauri::State<LinkConverter>,
+) -> Result<Conversion, ConversionError> {
     let state = state_manager.get_state();
-    
-    try_convert_with_all_platforms(&state, &LINK_CONVERTER, &url)
-        .ok_or_else(|| "Unable to convert link".to_string())
+
+    explain_conversion_with_all_platforms(&state, &link_converter, &url)
+}
+
+#[tauri::command]
+pub fn convert_text(
+    text: String,
+    state_manager: tauri::State<StateManager>,
+    link_converter: tauri::State<LinkConverter>,
+) -> Result<String, String> {
+    let state = state_manager.get_state();
+
+    try_convert_text_with_all_platforms(&state, &link_converter, &text)
+        .ok_or_else(|| "No convertible links found".to_string())
+}
+
+#[tauri::command]
+pub fn canonicalize_link(
+    url: String,
+    state_manager: tauri::State<StateManager>,
+    link_converter: tauri::State<LinkConverter>,
+) -> Result<String, String> {
+    let state = state_manager.get_state();
+
+    try_canonicalize_with_all_platforms(&state, &link_converter, &url)
+        .ok_or_else(|| "Not a known frontend link".to_string())
 }
 
 #[tauri::command]
@@ -174,9 +508,10 @@ pub fn start_clipboard_monitor(state_manager: tauri::State<StateManager>) -> Res
             };
 
             let state = app_handle.state::<StateManager>().get_state();
+            let link_converter = app_handle.state::<LinkConverter>();
             
-            // Try to convert the link with any available platform
-            if let Some(converted) = try_convert_with_all_platforms(&state, &LINK_CONVERTER, &content) {
+            // Try to convert the link (or every link in the text) with any available platform
+            if let Some(converted) = convert_clipboard_content(&state, &link_converter, &content) {
                 if let Err(e) = update_clipboard_and_notify(&app_handle, &content, &converted) {
                     eprintln!("Failed to update clipboard: {}", e);
                 }
@@ -1,5 +1,10 @@
+use crate::services::converters::custom::CustomFrontend;
+use crate::services::converters::options::ConverterOptions;
+use crate::services::converters::privacy::InstanceSelection;
+use crate::services::query_params::{filter_query, param_matches};
 use config::{Config, ConfigError, File};
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
+use std::collections::BTreeMap;
 use std::fs;
 use std::path::Path;
 
@@ -12,7 +17,12 @@ pub enum Platform {
     Bluesky,
     Instagram,
     Reddit,
+    Threads,
     Tiktok,
+    Pixiv,
+    Youtube,
+    Music,
+    Fediverse,
 }
 
 #[derive(Debug, Serialize, Deserialize, Hash, Eq, PartialEq, Clone)]
@@ -22,6 +32,11 @@ pub enum TwitterConverters {
     Vxtwitter,
     Fixupx,
     Fixvx,
+    /// Nitter instances from the privacy frontend settings
+    Nitter,
+    /// User-defined converter from the converter definitions file or the platform's custom frontends
+    #[serde(untagged)]
+    Custom(String),
 }
 
 #[derive(Debug, Serialize, Deserialize, Hash, Eq, PartialEq, Clone)]
@@ -32,6 +47,9 @@ pub enum BlueskyConverters {
     Vxbsky,
     Bskye,
     Bskyx,
+    /// User-defined converter from the converter definitions file or the platform's custom frontends
+    #[serde(untagged)]
+    Custom(String),
 }
 
 #[derive(Debug, Serialize, Deserialize, Hash, Eq, PartialEq, Clone)]
@@ -40,6 +58,11 @@ pub enum TikTokConverters {
     Tntok,
     Tfxktok,
     Tiktokez,
+    /// ProxiTok instances from the privacy frontend settings
+    Proxitok,
+    /// User-defined converter from the converter definitions file or the platform's custom frontends
+    #[serde(untagged)]
+    Custom(String),
 }
 
 #[derive(Debug, Serialize, Deserialize, Hash, Eq, PartialEq, Clone)]
@@ -49,7 +72,105 @@ pub enum InstagramConverters {
     Kkinstagram,
     Instagramez,
     Eeinstagram,
+    /// User-defined converter from the converter definitions file or the platform's custom frontends
+    #[serde(untagged)]
+    Custom(String),
 }
+
+#[derive(Debug, Serialize, Deserialize, Hash, Eq, PartialEq, Clone)]
+#[serde(rename_all = "lowercase")]
+pub enum RedditConverters {
+    Rxddit,
+    Vxreddit,
+    /// Redlib instances from the privacy frontend settings
+    Redlib,
+    /// User-defined converter from the converter definitions file or the platform's custom frontends
+    #[serde(untagged)]
+    Custom(String),
+}
+
+#[derive(Debug, Serialize, Deserialize, Hash, Eq, PartialEq, Clone)]
+#[serde(rename_all = "lowercase")]
+pub enum ThreadsConverters {
+    Fixthreads,
+    Vxthreads,
+    /// User-defined converter from the converter definitions file or the platform's custom frontends
+    #[serde(untagged)]
+    Custom(String),
+}
+
+#[derive(Debug, Serialize, Deserialize, Hash, Eq, PartialEq, Clone)]
+#[serde(rename_all = "lowercase")]
+pub enum PixivConverters {
+    Phixiv,
+    Ppxiv,
+    /// User-defined converter from the converter definitions file or the platform's custom frontends
+    #[serde(untagged)]
+    Custom(String),
+}
+
+#[derive(Debug, Serialize, Deserialize, Hash, Eq, PartialEq, Clone)]
+#[serde(rename_all = "lowercase")]
+pub enum YoutubeConverters {
+    /// Full youtube.com/watch links
+    Watch,
+    /// youtu.be short links
+    Short,
+    /// Invidious instances from the privacy frontend settings
+    Invidious,
+    /// User-defined converter from the converter definitions file or the platform's custom frontends
+    #[serde(untagged)]
+    Custom(String),
+}
+
+#[derive(Debug, Serialize, Deserialize, Hash, Eq, PartialEq, Clone)]
+#[serde(rename_all = "lowercase")]
+pub enum MusicConverters {
+    Songlink,
+    /// User-defined converter from the converter definitions file or the platform's custom frontends
+    #[serde(untagged)]
+    Custom(String),
+}
+
+#[derive(Debug, Serialize, Deserialize, Hash, Eq, PartialEq, Clone)]
+#[serde(rename_all = "lowercase")]
+pub enum FediverseConverters {
+    /// The embed frontend from the fediverse settings
+    Embed,
+    /// The home instance's authorize_interaction page
+    Home,
+    /// User-defined converter from the converter definitions file or the platform's custom frontends
+    #[serde(untagged)]
+    Custom(String),
+}
+
+/// Name of a converter as stored in the config file (e.g. "vxtwitter")
+pub fn converter_name<T: Serialize>(converter: &T) -> String {
+    serde_json::to_string(converter)
+        .unwrap_or_default()
+        .trim_matches('"')
+        .to_string()
+}
+
+/// Reads a preference list, or the single (possibly empty) `selected` converter of older config files
+fn preference_list<'de, D, T>(deserializer: D) -> Result<Vec<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum OneOrMany<T> {
+        Many(Vec<T>),
+        One(Option<T>),
+    }
+
+    Ok(match OneOrMany::deserialize(deserializer)? {
+        OneOrMany::Many(converters) => converters,
+        OneOrMany::One(converter) => converter.into_iter().collect(),
+    })
+}
+
 // --- Platform Source Definitions ---
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -63,13 +184,114 @@ pub enum PlatformSource {
     Tiktok(PlatformConverters<TikTokConverters>),
     #[serde(rename = "instagram")]
     Instagram(PlatformConverters<InstagramConverters>),
+    #[serde(rename = "reddit")]
+    Reddit(PlatformConverters<RedditConverters>),
+    #[serde(rename = "threads")]
+    Threads(PlatformConverters<ThreadsConverters>),
+    #[serde(rename = "pixiv")]
+    Pixiv(PlatformConverters<PixivConverters>),
+    #[serde(rename = "youtube")]
+    Youtube(PlatformConverters<YoutubeConverters>),
+    #[serde(rename = "music")]
+    Music(PlatformConverters<MusicConverters>),
+    #[serde(rename = "fediverse")]
+    Fediverse(PlatformConverters<FediverseConverters>),
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(bound(deserialize = "T: Deserialize<'de> + PlatformDefaults"))]
 pub struct PlatformConverters<T> {
     pub enabled: bool,
     pub converters: Vec<T>,
-    pub selected: Option<T>,
+    /// Converters in order of preference. Conversions use the first one not marked unavailable.
+    #[serde(default, alias = "selected", deserialize_with = "preference_list")]
+    pub preferred: Vec<T>,
+    /// Converters skipped until they are restored, e.g. while their frontend is down
+    #[serde(default)]
+    pub unavailable: Vec<T>,
+    #[serde(default = "T::default_query_policy")]
+    pub query_policy: QueryPolicy,
+    /// Modes of the converters, e.g. direct media links
+    #[serde(default)]
+    pub options: ConverterOptions,
+    /// Self-hosted frontends, selectable like the built-in converters
+    #[serde(default)]
+    pub custom: Vec<CustomFrontend>,
+}
+
+/// Settings that differ per platform, used when a config file predates them
+pub trait PlatformDefaults {
+    fn default_query_policy() -> QueryPolicy;
+}
+
+// --- Query Parameter Policy ---
+
+/// Which query parameters survive on a platform's links, applied whether or not conversion is enabled.
+/// Parameter names ending in `*` match any suffix, e.g. `utm_*`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(tag = "mode", content = "params", rename_all = "lowercase")]
+pub enum QueryPolicy {
+    /// Remove the listed parameters and keep everything else
+    Strip(Vec<String>),
+    /// Keep only the listed parameters
+    Keep(Vec<String>),
+}
+
+// --- Clipboard Settings ---
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(default)]
+pub struct ClipboardSettings {
+    /// Convert every supported link inside copied text instead of only whole-link clipboards
+    pub scan_text: bool,
+    /// Turn links on known frontends back into canonical platform URLs instead of converting them
+    pub canonicalize: bool,
+    /// Rewrite links already on another known frontend to the converter in use. Off by default,
+    /// since the sender may have picked that frontend on purpose
+    pub retarget: bool,
+    /// Recover links wrapped by redirect services (Google, Facebook, SafeLinks, ...) before converting them
+    pub unwrap_redirects: bool,
+}
+
+// --- ClearURLs Settings ---
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(default)]
+pub struct ClearUrlsSettings {
+    /// Clean every clipboard link with the ClearURLs rules loaded from the app data directory
+    pub enabled: bool,
+}
+
+// --- Fediverse Settings ---
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct FediverseSettings {
+    /// Mastodon-compatible instances whose post links are recognised, e.g. "mastodon.social"
+    pub instances: Vec<String>,
+    /// Instance the "home" converter opens posts on
+    pub home_instance: String,
+    /// Embed frontend for the "embed" converter; it receives the original instance as the first path segment
+    pub embed_frontend: String,
+}
+
+// --- Normalisation Settings ---
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct NormalizeSettings {
+    /// Normalisation rules the user switched off, e.g. "youtube-mobile"
+    pub disabled_rules: Vec<String>,
+}
+
+// --- Privacy Frontend Settings ---
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct PrivacyFrontendSettings {
+    /// Instances of the platform's privacy frontend, e.g. "nitter.net"
+    pub instances: Vec<String>,
+    pub selection: InstanceSelection,
 }
 
 // --- SourcesConfig ---
@@ -77,10 +299,121 @@ pub struct PlatformConverters<T> {
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct SourcesConfig {
     pub sources: Vec<PlatformSource>,
+    #[serde(default)]
+    pub clipboard: ClipboardSettings,
+    #[serde(default)]
+    pub clear_urls: ClearUrlsSettings,
+    #[serde(default)]
+    pub fediverse: FediverseSettings,
+    #[serde(default)]
+    pub normalize: NormalizeSettings,
+    /// Privacy frontend instances, keyed by platform name
+    #[serde(default)]
+    pub privacy: BTreeMap<String, PrivacyFrontendSettings>,
 }
 
 // --- Impl ---
 
+impl PlatformDefaults for TwitterConverters {
+    fn default_query_policy() -> QueryPolicy {
+        QueryPolicy::strip(&["s", "t", "ref_src", "ref_url", "utm_*"])
+    }
+}
+
+impl PlatformDefaults for BlueskyConverters {
+    fn default_query_policy() -> QueryPolicy {
+        QueryPolicy::default()
+    }
+}
+
+impl PlatformDefaults for TikTokConverters {
+    fn default_query_policy() -> QueryPolicy {
+        QueryPolicy::strip(&["_r", "_t", "is_from_webapp", "sender_device", "sender_web_id", "utm_*"])
+    }
+}
+
+impl PlatformDefaults for InstagramConverters {
+    fn default_query_policy() -> QueryPolicy {
+        QueryPolicy::strip(&["igsh", "igshid", "utm_*"])
+    }
+}
+
+impl PlatformDefaults for RedditConverters {
+    fn default_query_policy() -> QueryPolicy {
+        QueryPolicy::strip(&["share_id", "ref", "ref_source", "utm_*"])
+    }
+}
+
+impl PlatformDefaults for ThreadsConverters {
+    fn default_query_policy() -> QueryPolicy {
+        QueryPolicy::strip(&["xmt", "slof", "igshid", "utm_*"])
+    }
+}
+
+impl PlatformDefaults for PixivConverters {
+    fn default_query_policy() -> QueryPolicy {
+        // Legacy member_illust.php links identify the artwork by query parameters
+        QueryPolicy::Keep(vec!["illust_id".to_string(), "page".to_string()])
+    }
+}
+
+impl PlatformDefaults for YoutubeConverters {
+    fn default_query_policy() -> QueryPolicy {
+        QueryPolicy::strip(&["si", "feature", "pp", "utm_*"])
+    }
+}
+
+impl PlatformDefaults for MusicConverters {
+    fn default_query_policy() -> QueryPolicy {
+        QueryPolicy::strip(&["si", "ls", "app", "context", "utm_*"])
+    }
+}
+
+impl PlatformDefaults for FediverseConverters {
+    fn default_query_policy() -> QueryPolicy {
+        QueryPolicy::default()
+    }
+}
+
+impl QueryPolicy {
+    pub fn strip(params: &[&str]) -> Self {
+        QueryPolicy::Strip(params.iter().map(|p| p.to_string()).collect())
+    }
+
+    /// Apply the policy to the query string of a URL
+    pub fn apply(&self, url: &str) -> String {
+        let listed = |name: &str, params: &[String]| params.iter().any(|p| param_matches(name, p));
+
+        match self {
+            QueryPolicy::Strip(params) => filter_query(url, |name| !listed(name, params)),
+            QueryPolicy::Keep(params) => filter_query(url, |name| listed(name, params)),
+        }
+    }
+}
+
+impl Default for QueryPolicy {
+    fn default() -> Self {
+        QueryPolicy::strip(&["utm_*"])
+    }
+}
+
+impl Default for ClipboardSettings {
+    fn default() -> Self {
+        ClipboardSettings {
+            scan_text: true,
+            canonicalize: false,
+            retarget: false,
+            unwrap_redirects: true,
+        }
+    }
+}
+
+impl Default for ClearUrlsSettings {
+    fn default() -> Self {
+        ClearUrlsSettings { enabled: true }
+    }
+}
+
 impl Default for SourcesConfig {
     fn default() -> Self {
         SourcesConfig {
@@ -92,8 +425,13 @@ impl Default for SourcesConfig {
                         TwitterConverters::Vxtwitter,
                         TwitterConverters::Fixupx,
                         TwitterConverters::Fixvx,
+                        TwitterConverters::Nitter,
                     ],
-                    selected: Some(TwitterConverters::Vxtwitter),
+                    preferred: vec![TwitterConverters::Vxtwitter, TwitterConverters::Fxtwitter],
+                    unavailable: Vec::new(),
+                    query_policy: TwitterConverters::default_query_policy(),
+                    options: ConverterOptions::default(),
+                    custom: Vec::new(),
                 }),
                 PlatformSource::Bluesky(PlatformConverters {
                     enabled: true,
@@ -104,15 +442,24 @@ impl Default for SourcesConfig {
                         BlueskyConverters::Bskye,
                         BlueskyConverters::Bskyx,
                     ],
-                    selected: Some(BlueskyConverters::Bsky),
+                    preferred: vec![BlueskyConverters::Bsky],
+                    unavailable: Vec::new(),
+                    query_policy: BlueskyConverters::default_query_policy(),
+                    options: ConverterOptions::default(),
+                    custom: Vec::new(),
                 }),
                 PlatformSource::Tiktok(PlatformConverters {
                     enabled: true,
                     converters: vec![
                         TikTokConverters::Tfxktok,
                         TikTokConverters::Tiktokez,
+                        TikTokConverters::Proxitok,
                     ],
-                    selected: Some(TikTokConverters::Tfxktok),
+                    preferred: vec![TikTokConverters::Tfxktok],
+                    unavailable: Vec::new(),
+                    query_policy: TikTokConverters::default_query_policy(),
+                    options: ConverterOptions::default(),
+                    custom: Vec::new(),
                 }),
                 PlatformSource::Instagram(PlatformConverters {
                     enabled: true,
@@ -122,9 +469,90 @@ impl Default for SourcesConfig {
                         InstagramConverters::Instagramez,
                         InstagramConverters::Eeinstagram,
                     ],
-                    selected: Some(InstagramConverters::Kkinstagram),
+                    preferred: vec![InstagramConverters::Kkinstagram],
+                    unavailable: Vec::new(),
+                    query_policy: InstagramConverters::default_query_policy(),
+                    options: ConverterOptions::default(),
+                    custom: Vec::new(),
+                }),
+                PlatformSource::Reddit(PlatformConverters {
+                    enabled: true,
+                    converters: vec![
+                        RedditConverters::Rxddit,
+                        RedditConverters::Vxreddit,
+                        RedditConverters::Redlib,
+                    ],
+                    preferred: vec![RedditConverters::Rxddit],
+                    unavailable: Vec::new(),
+                    query_policy: RedditConverters::default_query_policy(),
+                    options: ConverterOptions::default(),
+                    custom: Vec::new(),
+                }),
+                PlatformSource::Threads(PlatformConverters {
+                    enabled: true,
+                    converters: vec![
+                        ThreadsConverters::Fixthreads,
+                        ThreadsConverters::Vxthreads,
+                    ],
+                    preferred: vec![ThreadsConverters::Fixthreads],
+                    unavailable: Vec::new(),
+                    query_policy: ThreadsConverters::default_query_policy(),
+                    options: ConverterOptions::default(),
+                    custom: Vec::new(),
+                }),
+                PlatformSource::Pixiv(PlatformConverters {
+                    enabled: true,
+                    converters: vec![
+                        PixivConverters::Phixiv,
+                        PixivConverters::Ppxiv,
+                    ],
+                    preferred: vec![PixivConverters::Phixiv],
+                    unavailable: Vec::new(),
+                    query_policy: PixivConverters::default_query_policy(),
+                    options: ConverterOptions::default(),
+                    custom: Vec::new(),
+                }),
+                PlatformSource::Youtube(PlatformConverters {
+                    enabled: true,
+                    converters: vec![
+                        YoutubeConverters::Watch,
+                        YoutubeConverters::Short,
+                        YoutubeConverters::Invidious,
+                    ],
+                    preferred: vec![YoutubeConverters::Watch],
+                    unavailable: Vec::new(),
+                    query_policy: YoutubeConverters::default_query_policy(),
+                    options: ConverterOptions::default(),
+                    custom: Vec::new(),
+                }),
+                PlatformSource::Music(PlatformConverters {
+                    enabled: true,
+                    converters: vec![MusicConverters::Songlink],
+                    preferred: vec![MusicConverters::Songlink],
+                    unavailable: Vec::new(),
+                    query_policy: MusicConverters::default_query_policy(),
+                    options: ConverterOptions::default(),
+                    custom: Vec::new(),
+                }),
+                // Nothing is recognised until the user lists their instances
+                PlatformSource::Fediverse(PlatformConverters {
+                    enabled: false,
+                    converters: vec![
+                        FediverseConverters::Home,
+                        FediverseConverters::Embed,
+                    ],
+                    preferred: vec![FediverseConverters::Home],
+                    unavailable: Vec::new(),
+                    query_policy: FediverseConverters::default_query_policy(),
+                    options: ConverterOptions::default(),
+                    custom: Vec::new(),
                 }),
             ],
+            clipboard: ClipboardSettings::default(),
+            clear_urls: ClearUrlsSettings::default(),
+            fediverse: FediverseSettings::default(),
+            normalize: NormalizeSettings::default(),
+            privacy: BTreeMap::new(),
         }
     }
 }
@@ -157,7 +585,32 @@ impl SourcesConfig {
         let settings = Config::builder()
             .add_source(File::with_name(path.strip_suffix(".yaml").unwrap_or(path)))
             .build()?;
-        settings.try_deserialize()
+        let mut config: Self = settings.try_deserialize()?;
+        config.add_missing_sources();
+        config.add_missing_converters();
+        Ok(config)
+    }
+
+    /// Append default sources for platforms added after the config file was created
+    pub fn add_missing_sources(&mut self) {
+        for default_source in Self::default().sources {
+            let platform = default_source.get_platform_type();
+            if !self.sources.iter().any(|source| source.get_platform_type() == platform) {
+                self.sources.push(default_source);
+            }
+        }
+    }
+
+    /// Append default converters added after the config file was created, e.g. privacy frontends
+    pub fn add_missing_converters(&mut self) {
+        for default_source in Self::default().sources {
+            let platform = default_source.get_platform_type();
+            if let Some(source) = self.sources.iter_mut().find(|source| source.get_platform_type() == platform) {
+                for name in default_source.get_operations().converter_names() {
+                    source.get_operations_mut().add_converter(&name);
+                }
+            }
+        }
     }
 
     pub fn save_to_file(&self, path: &str) {
@@ -1,5 +1,10 @@
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
-use super::app_config::PlatformSource;
+use std::collections::BTreeMap;
+use crate::services::converters::custom::CustomFrontend;
+use crate::services::converters::options::{ConverterOption, ConverterOptions};
+use crate::platform_ops::PlatformOperations;
+use super::app_config::{converter_name, ClearUrlsSettings, ClipboardSettings, FediverseSettings, NormalizeSettings, PlatformConverters, PlatformSource, PrivacyFrontendSettings, QueryPolicy};
 
 // --- Frontend Config Structures ---
 
@@ -13,7 +18,21 @@ pub struct FrontendPlatformMetadata {
 pub struct FrontendConverterConfig {
     pub enabled: bool,
     pub converters: Vec<String>,
+    /// First converter of the preference list
     pub selected: String,
+    /// Converters in order of preference, the first one being `selected`
+    pub preferred: Vec<String>,
+    pub unavailable: Vec<String>,
+    /// Converter links are converted with, skipping unavailable ones and ones that cannot run
+    pub active: Option<String>,
+    pub query_policy: QueryPolicy,
+    /// Enabled, but the active converter leaves vanilla links unchanged
+    pub passthrough: bool,
+    pub options: ConverterOptions,
+    /// Modes the active converter exposes
+    pub supported_options: Vec<ConverterOption>,
+    /// Self-hosted frontends, also listed in `converters`
+    pub custom: Vec<CustomFrontend>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -26,6 +45,45 @@ pub struct FrontendPlatformSource {
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct FrontendAppConfig {
     pub sources: Vec<FrontendPlatformSource>,
+    pub clipboard: ClipboardSettings,
+    pub clear_urls: FrontendClearUrlsConfig,
+    pub fediverse: FediverseSettings,
+    pub normalize: FrontendNormalizeConfig,
+    /// Privacy frontend settings for every platform that has one, keyed by platform name
+    pub privacy: BTreeMap<String, FrontendPrivacyConfig>,
+}
+
+/// ClearURLs settings along with the number of rule providers that were loaded
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct FrontendClearUrlsConfig {
+    #[serde(flatten)]
+    pub settings: ClearUrlsSettings,
+    pub providers: usize,
+}
+
+/// Normalisation settings along with every rule the normaliser knows
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct FrontendNormalizeConfig {
+    #[serde(flatten)]
+    pub settings: NormalizeSettings,
+    pub rules: Vec<String>,
+}
+
+/// Privacy frontend settings of a platform along with the converter name of its frontend
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct FrontendPrivacyConfig {
+    #[serde(flatten)]
+    pub settings: PrivacyFrontendSettings,
+    pub frontend: String,
+}
+
+/// A configured converter that the registry cannot run, reported to the frontend at startup
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ConfigWarning {
+    pub platform: String,
+    pub converter: Option<String>,
+    pub fallback: Option<String>,
+    pub message: String,
 }
 
 // --- Helper Functions ---
@@ -52,6 +110,26 @@ pub fn get_platform_metadata(platform: &str) -> FrontendPlatformMetadata {
             title: "Reddit".to_string(),
             icon: "reddit".to_string(),
         },
+        "threads" => FrontendPlatformMetadata {
+            title: "Threads".to_string(),
+            icon: "threads".to_string(),
+        },
+        "pixiv" => FrontendPlatformMetadata {
+            title: "Pixiv".to_string(),
+            icon: "pixiv".to_string(),
+        },
+        "youtube" => FrontendPlatformMetadata {
+            title: "YouTube".to_string(),
+            icon: "youtube".to_string(),
+        },
+        "music" => FrontendPlatformMetadata {
+            title: "Music".to_string(),
+            icon: "spotify".to_string(),
+        },
+        "fediverse" => FrontendPlatformMetadata {
+            title: "Fediverse".to_string(),
+            icon: "mastodon".to_string(),
+        },
         _ => FrontendPlatformMetadata {
             title: platform.chars().next().unwrap().to_uppercase().to_string() + &platform[1..],
             icon: platform.to_string(),
@@ -59,43 +137,64 @@ pub fn get_platform_metadata(platform: &str) -> FrontendPlatformMetadata {
     }
 }
 
-pub fn transform_platform_source(source: &PlatformSource) -> FrontendPlatformSource {
-    match source {
-        PlatformSource::Twitter(data) => FrontendPlatformSource {
-            platform: "twitter".to_string(),
-            metadata: get_platform_metadata("twitter"),
-            data: FrontendConverterConfig {
-                enabled: data.enabled,
-                converters: data.converters.iter().map(|c| format!("{:?}", c).to_lowercase()).collect(),
-                selected: data.selected.as_ref().map(|s| format!("{:?}", s).to_lowercase()).unwrap_or_default(),
-            },
-        },
-        PlatformSource::Bluesky(data) => FrontendPlatformSource {
-            platform: "bluesky".to_string(),
-            metadata: get_platform_metadata("bluesky"),
-            data: FrontendConverterConfig {
-                enabled: data.enabled,
-                converters: data.converters.iter().map(|c| format!("{:?}", c).to_lowercase()).collect(),
-                selected: data.selected.as_ref().map(|s| format!("{:?}", s).to_lowercase()).unwrap_or_default(),
-            },
-        },
-        PlatformSource::Tiktok(data) => FrontendPlatformSource {
-            platform: "tiktok".to_string(),
-            metadata: get_platform_metadata("tiktok"),
-            data: FrontendConverterConfig {
-                enabled: data.enabled,
-                converters: data.converters.iter().map(|c| format!("{:?}", c).to_lowercase()).collect(),
-                selected: data.selected.as_ref().map(|s| format!("{:?}", s).to_lowercase()).unwrap_or_default(),
-            },
-        },
-        PlatformSource::Instagram(data) => FrontendPlatformSource {
-            platform: "instagram".to_string(),
-            metadata: get_platform_metadata("instagram"),
-            data: FrontendConverterConfig {
-                enabled: data.enabled,
-                converters: data.converters.iter().map(|c| format!("{:?}", c).to_lowercase()).collect(),
-                selected: data.selected.as_ref().map(|s| format!("{:?}", s).to_lowercase()).unwrap_or_default(),
-            },
-        },
+fn transform_converters<T: Serialize + DeserializeOwned + Clone>(
+    data: &PlatformConverters<T>,
+    available: &[String],
+    active: Option<&str>,
+    passthrough: bool,
+    supported_options: &[ConverterOption],
+) -> FrontendConverterConfig {
+    let mut converters: Vec<String> = data.converters.iter().map(converter_name).collect();
+
+    // Converters the registry supports beyond the configured list (e.g. user definitions) come last
+    for name in available {
+        if !converters.iter().any(|c| c == name) {
+            converters.push(name.clone());
+        }
+    }
+
+    FrontendConverterConfig {
+        enabled: data.enabled,
+        converters,
+        selected: data.preferred.first().map(converter_name).unwrap_or_default(),
+        preferred: data.preferred_converters(),
+        unavailable: data.unavailable_converters(),
+        active: active.map(String::from),
+        query_policy: data.query_policy.clone(),
+        passthrough: data.enabled && passthrough,
+        options: data.options.clone(),
+        supported_options: supported_options.to_vec(),
+        custom: data.custom.clone(),
+    }
+}
+
+/// `active` is the converter links are converted with, `passthrough` tells whether it keeps vanilla links unchanged, and
+/// `supported_options` lists the modes it exposes
+pub fn transform_platform_source(
+    source: &PlatformSource,
+    available: &[String],
+    active: Option<&str>,
+    passthrough: bool,
+    supported_options: &[ConverterOption],
+) -> FrontendPlatformSource {
+    let platform = source.get_platform_name();
+
+    let data = match source {
+        PlatformSource::Twitter(data) => transform_converters(data, available, active, passthrough, supported_options),
+        PlatformSource::Bluesky(data) => transform_converters(data, available, active, passthrough, supported_options),
+        PlatformSource::Tiktok(data) => transform_converters(data, available, active, passthrough, supported_options),
+        PlatformSource::Instagram(data) => transform_converters(data, available, active, passthrough, supported_options),
+        PlatformSource::Reddit(data) => transform_converters(data, available, active, passthrough, supported_options),
+        PlatformSource::Threads(data) => transform_converters(data, available, active, passthrough, supported_options),
+        PlatformSource::Pixiv(data) => transform_converters(data, available, active, passthrough, supported_options),
+        PlatformSource::Youtube(data) => transform_converters(data, available, active, passthrough, supported_options),
+        PlatformSource::Music(data) => transform_converters(data, available, active, passthrough, supported_options),
+        PlatformSource::Fediverse(data) => transform_converters(data, available, active, passthrough, supported_options),
+    };
+
+    FrontendPlatformSource {
+        platform: platform.to_string(),
+        metadata: get_platform_metadata(platform),
+        data,
     }
 }
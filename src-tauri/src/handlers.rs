@@ -1,4 +1,7 @@
+use crate::commands::set_converter_availability;
 use crate::services::clipboard::{ClipboardManager, SystemClipboard};
+use crate::services::link_converter::LinkConverter;
+use crate::state::StateManager;
 use crate::tray_menu::MenuId;
 use tauri::{AppHandle, Manager, Runtime};
 use once_cell::sync::Lazy;
@@ -35,6 +38,22 @@ pub fn handle_get_clipboard() {
     }
 }
 
+pub fn handle_toggle_converter<R: Runtime>(app: &AppHandle<R>, platform: &str, converter: &str) {
+    let state_manager = app.state::<StateManager>();
+    let link_converter = app.state::<LinkConverter>();
+
+    let is_unavailable = state_manager
+        .get_state()
+        .sources
+        .iter()
+        .find(|source| source.get_platform_name() == platform)
+        .is_some_and(|source| source.get_operations().unavailable_converters().iter().any(|c| c == converter));
+
+    if let Err(e) = set_converter_availability(&state_manager, &link_converter, platform, converter, is_unavailable) {
+        println!("Error: {}", e);
+    }
+}
+
 pub fn handle_hide_window<R: Runtime>(app: &AppHandle<R>) {
     with_main_window(app, |window| {
         let _ = window.hide();
@@ -83,6 +102,9 @@ pub fn handle_menu_event<R: Runtime>(app: &AppHandle<R>, menu_id_str: &str) {
             MenuId::Show => {
                 handle_show_window(app);
             }
+            MenuId::ToggleConverter { platform, converter } => {
+                handle_toggle_converter(app, &platform, &converter);
+            }
             MenuId::Quit => {
                 println!("Quit menu item clicked");
                 app.exit(0);
@@ -12,8 +12,14 @@ mod tray_menu;
 use crate::app::setup_app_exit_handler;
 use crate::commands::*;
 use crate::config::app_config::SourcesConfig;
-use crate::state::StateManager;
-use crate::tray_menu::menu::{create_menu, create_tray};
+use crate::platform_ops::{
+    apply_custom_frontends, apply_fediverse_settings, apply_normalize_settings, apply_privacy_settings,
+    validate_sources,
+};
+use crate::services::clear_urls::ClearUrlsRules;
+use crate::services::link_converter::LinkConverter;
+use crate::state::{ConfigWarnings, StateManager};
+use crate::tray_menu::menu::{create_menu, create_tray, refresh_tray_menu};
 use once_cell::sync::Lazy;
 use std::sync::Mutex;
 
@@ -46,6 +52,42 @@ pub fn run() {
 
             app.manage(state_manager);
 
+            // Load the link converters, including user definitions stored next to the config
+            let mut link_converter = LinkConverter::with_definitions_file(&appdata_path.join("converters.yaml"));
+
+            // Generic cleaning rules in the ClearURLs data.min.json format, if the user provided them
+            match ClearUrlsRules::from_file(&appdata_path.join("clearurls.json")) {
+                Ok((rules, errors)) => {
+                    for error in errors {
+                        eprintln!("Skipping ClearURLs provider: {}", error);
+                    }
+                    link_converter.set_clear_urls(rules);
+                }
+                Err(e) => eprintln!("Failed to load ClearURLs rules: {}", e),
+            }
+
+            let state = app.state::<StateManager>().get_state();
+            if let Err(e) = apply_fediverse_settings(&state.fediverse, &link_converter) {
+                eprintln!("Failed to apply fediverse settings: {}", e);
+            }
+            apply_normalize_settings(&state.normalize, &link_converter);
+            apply_privacy_settings(&state.privacy, &link_converter);
+            for error in apply_custom_frontends(&state, &link_converter) {
+                eprintln!("Skipping custom converter: {}", error);
+            }
+
+            // Report configured converters that cannot run; the frontend fetches these once it has loaded
+            let warnings = validate_sources(&state, &link_converter);
+            for warning in &warnings {
+                eprintln!("Config warning: {}", warning.message);
+            }
+
+            app.manage(link_converter);
+            app.manage(ConfigWarnings(Mutex::new(warnings)));
+
+            // Add the converters of the loaded config to the tray
+            refresh_tray_menu(handle);
+
             // Load or create the configuration
             let config = SourcesConfig::from_file_or_default(
                 config_path
@@ -70,12 +112,25 @@ pub fn run() {
             greet_from_app,
             get_config,
             get_frontend_config,
+            get_config_warnings,
             update_config,
             get_state,
             update_state,
             toggle_platform,
             select_converter,
+            set_preferred_converters,
+            set_converter_available,
+            set_query_policy,
+            set_converter_options,
+            update_custom_converters,
             convert_link,
+            convert_text,
+            canonicalize_link,
+            update_clipboard_settings,
+            update_clear_urls_settings,
+            update_fediverse_settings,
+            update_normalize_settings,
+            update_privacy_settings,
             start_clipboard_monitor,
         ])
         .run(tauri::generate_context!())
@@ -1,16 +1,45 @@
-use crate::config::app_config::{Platform, PlatformConverters, PlatformSource, SourcesConfig};
+use crate::config::frontend::ConfigWarning;
+use crate::config::app_config::{converter_name, FediverseSettings, NormalizeSettings, Platform, PlatformConverters, PlatformSource, PrivacyFrontendSettings, QueryPolicy, SourcesConfig};
+use crate::services::converters::conversion::{Conversion, ConversionError};
+use crate::services::converters::custom::CustomFrontend;
+use crate::services::converters::options::ConverterOptions;
+use crate::services::converters::LinkConverterStrategy;
 use crate::services::link_converter::LinkConverter;
+use crate::services::query_params::{append_query, filter_query, query_string};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::collections::BTreeMap;
 
 /// Generic platform operations trait for common platform functionality
 pub trait PlatformOperations {
     fn is_enabled(&self) -> bool;
     fn set_enabled(&mut self, enabled: bool);
-    fn get_selected_converter(&self) -> Option<String>;
+    fn preferred_converters(&self) -> Vec<String>;
+    fn set_preferred_converters(&mut self, converter_names: &[String]) -> bool;
+    fn unavailable_converters(&self) -> Vec<String>;
+    fn set_converter_available(&mut self, converter_name: &str, available: bool) -> bool;
+    fn converter_names(&self) -> Vec<String>;
+    /// Append a converter to the configured list unless it is already there
+    fn add_converter(&mut self, converter_name: &str) -> bool;
+    fn query_policy(&self) -> &QueryPolicy;
+    fn set_query_policy(&mut self, policy: QueryPolicy);
+    fn options(&self) -> &ConverterOptions;
+    fn set_options(&mut self, options: ConverterOptions);
+    fn custom_frontends(&self) -> &[CustomFrontend];
+    fn set_custom_frontends(&mut self, frontends: Vec<CustomFrontend>);
+    /// Move a converter of the configured list to the front of the preference list
     fn set_converter_by_name(&mut self, converter_name: &str) -> bool;
-    fn try_convert_link(&self, link_converter: &LinkConverter, url: &str, platform_name: &str) -> Option<String>;
+    /// Prefer a converter outside the built-in list, e.g. one from the user's definitions file
+    fn set_custom_converter(&mut self, converter_name: &str) -> bool;
+    fn try_convert_link(&self, link_converter: &LinkConverter, url: &str, platform_name: &str) -> Result<Conversion, ConversionError>;
+    fn try_retarget_link(&self, link_converter: &LinkConverter, url: &str, platform_name: &str) -> Option<String>;
+    fn try_canonicalize_link(&self, link_converter: &LinkConverter, url: &str, platform_name: &str) -> Option<String>;
 }
 
-impl PlatformOperations for PlatformConverters<crate::config::app_config::TwitterConverters> {
+impl<T> PlatformOperations for PlatformConverters<T>
+where
+    T: Serialize + DeserializeOwned + Clone,
+{
     fn is_enabled(&self) -> bool {
         self.enabled
     }
@@ -19,173 +48,142 @@ impl PlatformOperations for PlatformConverters<crate::config::app_config::Twitte
         self.enabled = enabled;
     }
 
-    fn get_selected_converter(&self) -> Option<String> {
-        self.selected.as_ref().and_then(|c| {
-            serde_json::to_string(c).ok().map(|s| s.trim_matches('"').to_string())
-        })
+    fn preferred_converters(&self) -> Vec<String> {
+        self.preferred.iter().map(converter_name).collect()
     }
 
-    fn set_converter_by_name(&mut self, converter_name: &str) -> bool {
-        if let Some(found) = self.converters.iter().find(|c| {
-            let serialized = serde_json::to_string(c)
-                .unwrap_or_default()
-                .trim_matches('"')
-                .to_string();
-            serialized == converter_name
-        }) {
-            self.selected = Some(found.clone());
-            true
-        } else {
-            false
+    fn set_preferred_converters(&mut self, converter_names: &[String]) -> bool {
+        let mut preferred: Vec<T> = Vec::new();
+        for name in converter_names {
+            let Some(converter) = parse_converter::<T>(name) else {
+                return false;
+            };
+            if !preferred.iter().any(|c| converter_name(c) == *name) {
+                preferred.push(converter);
+            }
         }
+        self.preferred = preferred;
+        true
     }
 
-    fn try_convert_link(&self, link_converter: &LinkConverter, url: &str, platform_name: &str) -> Option<String> {
-        if self.enabled {
-            if let Some(selected) = &self.selected {
-                let converter_name = serde_json::to_string(selected)
-                    .unwrap_or_default()
-                    .trim_matches('"')
-                    .to_string();
-                return link_converter.convert_link(url, platform_name, &converter_name);
-            }
+    fn unavailable_converters(&self) -> Vec<String> {
+        self.unavailable.iter().map(converter_name).collect()
+    }
+
+    fn set_converter_available(&mut self, converter_name_str: &str, available: bool) -> bool {
+        let Some(converter) = parse_converter::<T>(converter_name_str) else {
+            return false;
+        };
+        self.unavailable.retain(|c| converter_name(c) != converter_name_str);
+        if !available {
+            self.unavailable.push(converter);
         }
-        None
+        true
     }
-}
 
-impl PlatformOperations for PlatformConverters<crate::config::app_config::BlueskyConverters> {
-    fn is_enabled(&self) -> bool {
-        self.enabled
+    fn converter_names(&self) -> Vec<String> {
+        self.converters.iter().map(converter_name).collect()
     }
 
-    fn set_enabled(&mut self, enabled: bool) {
-        self.enabled = enabled;
+    fn add_converter(&mut self, converter_name_str: &str) -> bool {
+        if self.converters.iter().any(|c| converter_name(c) == converter_name_str) {
+            return true;
+        }
+        match parse_converter::<T>(converter_name_str) {
+            Some(converter) => {
+                self.converters.push(converter);
+                true
+            }
+            None => false,
+        }
     }
 
-    fn get_selected_converter(&self) -> Option<String> {
-        self.selected.as_ref().and_then(|c| {
-            serde_json::to_string(c).ok().map(|s| s.trim_matches('"').to_string())
-        })
+    fn query_policy(&self) -> &QueryPolicy {
+        &self.query_policy
     }
 
-    fn set_converter_by_name(&mut self, converter_name: &str) -> bool {
-        if let Some(found) = self.converters.iter().find(|c| {
-            let serialized = serde_json::to_string(c)
-                .unwrap_or_default()
-                .trim_matches('"')
-                .to_string();
-            serialized == converter_name
-        }) {
-            self.selected = Some(found.clone());
-            true
-        } else {
-            false
-        }
+    fn set_query_policy(&mut self, policy: QueryPolicy) {
+        self.query_policy = policy;
     }
 
-    fn try_convert_link(&self, link_converter: &LinkConverter, url: &str, platform_name: &str) -> Option<String> {
-        if self.enabled {
-            if let Some(selected) = &self.selected {
-                let converter_name = serde_json::to_string(selected)
-                    .unwrap_or_default()
-                    .trim_matches('"')
-                    .to_string();
-                return link_converter.convert_link(url, platform_name, &converter_name);
-            }
-        }
-        None
+    fn options(&self) -> &ConverterOptions {
+        &self.options
     }
-}
 
-impl PlatformOperations for PlatformConverters<crate::config::app_config::TikTokConverters> {
-    fn is_enabled(&self) -> bool {
-        self.enabled
+    fn set_options(&mut self, options: ConverterOptions) {
+        self.options = options;
     }
 
-    fn set_enabled(&mut self, enabled: bool) {
-        self.enabled = enabled;
+    fn custom_frontends(&self) -> &[CustomFrontend] {
+        &self.custom
     }
 
-    fn get_selected_converter(&self) -> Option<String> {
-        self.selected.as_ref().and_then(|c| {
-            serde_json::to_string(c).ok().map(|s| s.trim_matches('"').to_string())
-        })
+    fn set_custom_frontends(&mut self, frontends: Vec<CustomFrontend>) {
+        self.custom = frontends;
     }
 
-    fn set_converter_by_name(&mut self, converter_name: &str) -> bool {
-        if let Some(found) = self.converters.iter().find(|c| {
-            let serialized = serde_json::to_string(c)
-                .unwrap_or_default()
-                .trim_matches('"')
-                .to_string();
-            serialized == converter_name
-        }) {
-            self.selected = Some(found.clone());
+    fn set_converter_by_name(&mut self, converter_name_str: &str) -> bool {
+        if let Some(found) = self.converters.iter().find(|c| converter_name(*c) == converter_name_str) {
+            let found = found.clone();
+            prefer(&mut self.preferred, found);
             true
         } else {
             false
         }
     }
 
-    fn try_convert_link(&self, link_converter: &LinkConverter, url: &str, platform_name: &str) -> Option<String> {
-        if self.enabled {
-            if let Some(selected) = &self.selected {
-                let converter_name = serde_json::to_string(selected)
-                    .unwrap_or_default()
-                    .trim_matches('"')
-                    .to_string();
-                return link_converter.convert_link(url, platform_name, &converter_name);
+    fn set_custom_converter(&mut self, converter_name_str: &str) -> bool {
+        match parse_converter::<T>(converter_name_str) {
+            Some(converter) => {
+                prefer(&mut self.preferred, converter);
+                true
             }
+            None => false,
         }
-        None
     }
-}
 
-impl PlatformOperations for PlatformConverters<crate::config::app_config::InstagramConverters> {
-    fn is_enabled(&self) -> bool {
-        self.enabled
-    }
-
-    fn set_enabled(&mut self, enabled: bool) {
-        self.enabled = enabled;
-    }
+    fn try_convert_link(&self, link_converter: &LinkConverter, url: &str, platform_name: &str) -> Result<Conversion, ConversionError> {
+        // Settings only explain why a link was left alone if it belongs to this platform
+        if !link_converter.registry().recognizes(url, platform_name) {
+            return Err(ConversionError::NotSupported);
+        }
+        if !self.enabled {
+            return Err(ConversionError::PlatformDisabled { platform: platform_name.to_string() });
+        }
+        let active = resolve_converter(self, link_converter, platform_name)?;
 
-    fn get_selected_converter(&self) -> Option<String> {
-        self.selected.as_ref().and_then(|c| {
-            serde_json::to_string(c).ok().map(|s| s.trim_matches('"').to_string())
-        })
+        link_converter.try_convert_link(url, platform_name, &active, &self.options)
     }
 
-    fn set_converter_by_name(&mut self, converter_name: &str) -> bool {
-        if let Some(found) = self.converters.iter().find(|c| {
-            let serialized = serde_json::to_string(c)
-                .unwrap_or_default()
-                .trim_matches('"')
-                .to_string();
-            serialized == converter_name
-        }) {
-            self.selected = Some(found.clone());
-            true
-        } else {
-            false
+    fn try_retarget_link(&self, link_converter: &LinkConverter, url: &str, platform_name: &str) -> Option<String> {
+        if self.enabled {
+            if let Ok(active) = resolve_converter(self, link_converter, platform_name) {
+                return link_converter.retarget_link(url, platform_name, &active, &self.options);
+            }
         }
+        None
     }
 
-    fn try_convert_link(&self, link_converter: &LinkConverter, url: &str, platform_name: &str) -> Option<String> {
+    fn try_canonicalize_link(&self, link_converter: &LinkConverter, url: &str, platform_name: &str) -> Option<String> {
         if self.enabled {
-            if let Some(selected) = &self.selected {
-                let converter_name = serde_json::to_string(selected)
-                    .unwrap_or_default()
-                    .trim_matches('"')
-                    .to_string();
-                return link_converter.convert_link(url, platform_name, &converter_name);
-            }
+            return link_converter.canonicalize_link(url, platform_name);
         }
         None
     }
 }
 
+// Converter with the given name, which may be outside the built-in list
+fn parse_converter<T: DeserializeOwned>(name: &str) -> Option<T> {
+    serde_json::from_value(serde_json::Value::String(name.to_string())).ok()
+}
+
+// Move a converter to the front of a preference list, keeping the others as fallbacks
+fn prefer<T: Serialize>(preferred: &mut Vec<T>, converter: T) {
+    let name = converter_name(&converter);
+    preferred.retain(|c| converter_name(c) != name);
+    preferred.insert(0, converter);
+}
+
 // Helper methods for PlatformSource enum
 impl PlatformSource {
     pub fn get_platform_type(&self) -> Platform {
@@ -194,6 +192,12 @@ impl PlatformSource {
             PlatformSource::Bluesky(_) => Platform::Bluesky,
             PlatformSource::Tiktok(_) => Platform::Tiktok,
             PlatformSource::Instagram(_) => Platform::Instagram,
+            PlatformSource::Reddit(_) => Platform::Reddit,
+            PlatformSource::Threads(_) => Platform::Threads,
+            PlatformSource::Pixiv(_) => Platform::Pixiv,
+            PlatformSource::Youtube(_) => Platform::Youtube,
+            PlatformSource::Music(_) => Platform::Music,
+            PlatformSource::Fediverse(_) => Platform::Fediverse,
         }
     }
 
@@ -203,6 +207,12 @@ impl PlatformSource {
             PlatformSource::Bluesky(_) => "bluesky",
             PlatformSource::Tiktok(_) => "tiktok",
             PlatformSource::Instagram(_) => "instagram",
+            PlatformSource::Reddit(_) => "reddit",
+            PlatformSource::Threads(_) => "threads",
+            PlatformSource::Pixiv(_) => "pixiv",
+            PlatformSource::Youtube(_) => "youtube",
+            PlatformSource::Music(_) => "music",
+            PlatformSource::Fediverse(_) => "fediverse",
         }
     }
 
@@ -212,6 +222,12 @@ impl PlatformSource {
             PlatformSource::Bluesky(data) => data,
             PlatformSource::Tiktok(data) => data,
             PlatformSource::Instagram(data) => data,
+            PlatformSource::Reddit(data) => data,
+            PlatformSource::Threads(data) => data,
+            PlatformSource::Pixiv(data) => data,
+            PlatformSource::Youtube(data) => data,
+            PlatformSource::Music(data) => data,
+            PlatformSource::Fediverse(data) => data,
         }
     }
 
@@ -221,12 +237,41 @@ impl PlatformSource {
             PlatformSource::Bluesky(data) => data,
             PlatformSource::Tiktok(data) => data,
             PlatformSource::Instagram(data) => data,
+            PlatformSource::Reddit(data) => data,
+            PlatformSource::Threads(data) => data,
+            PlatformSource::Pixiv(data) => data,
+            PlatformSource::Youtube(data) => data,
+            PlatformSource::Music(data) => data,
+            PlatformSource::Fediverse(data) => data,
         }
     }
 
-    pub fn try_convert_link(&self, link_converter: &LinkConverter, url: &str) -> Option<String> {
+    pub fn try_convert_link(&self, link_converter: &LinkConverter, url: &str) -> Result<Conversion, ConversionError> {
         self.get_operations().try_convert_link(link_converter, url, self.get_platform_name())
     }
+
+    pub fn try_retarget_link(&self, link_converter: &LinkConverter, url: &str) -> Option<Conversion> {
+        let operations = self.get_operations();
+        let platform = self.get_platform_name();
+        let converted = operations.try_retarget_link(link_converter, url, platform)?;
+        let ids = link_converter
+            .canonicalize_link(url, platform)
+            .map(|canonical| link_converter.registry().extract_ids(&canonical, platform))
+            .unwrap_or_default();
+
+        Some(Conversion {
+            platform: platform.to_string(),
+            converter: resolve_converter(operations, link_converter, platform).unwrap_or_default(),
+            ids,
+            original: url.to_string(),
+            url: converted,
+            cleaned_only: false,
+        })
+    }
+
+    pub fn try_canonicalize_link(&self, link_converter: &LinkConverter, url: &str) -> Option<String> {
+        self.get_operations().try_canonicalize_link(link_converter, url, self.get_platform_name())
+    }
 }
 
 /// Parse platform string into Platform enum
@@ -236,6 +281,12 @@ pub fn parse_platform(platform_str: &str) -> Option<Platform> {
         "bluesky" => Some(Platform::Bluesky),
         "tiktok" => Some(Platform::Tiktok),
         "instagram" => Some(Platform::Instagram),
+        "reddit" => Some(Platform::Reddit),
+        "threads" => Some(Platform::Threads),
+        "pixiv" => Some(Platform::Pixiv),
+        "youtube" => Some(Platform::Youtube),
+        "music" => Some(Platform::Music),
+        "fediverse" => Some(Platform::Fediverse),
         _ => None,
     }
 }
@@ -251,9 +302,923 @@ where
         .map(|source| operation(source.get_operations_mut()))
 }
 
-/// Try to convert link using any available platform
-pub fn try_convert_with_all_platforms(state: &SourcesConfig, link_converter: &LinkConverter, url: &str) -> Option<String> {
+/// Converter a platform's links are converted with: the first preferred converter that the registry can
+/// run and that is not marked unavailable. When no preferred converter can run, e.g. because the converter
+/// definitions failed to load, the configured list and then anything the registry offers stand in.
+/// The saved config is left alone, so the preferred converters take over again once they load.
+pub fn resolve_converter(data: &dyn PlatformOperations, link_converter: &LinkConverter, platform: &str) -> Result<String, ConversionError> {
+    let available = link_converter.registry().available_converters(platform);
+    let can_run = |name: &str| available.iter().any(|a| a.eq_ignore_ascii_case(name));
+    let unavailable = data.unavailable_converters();
+
+    let preferred: Vec<String> = data.preferred_converters().into_iter().filter(|name| can_run(name)).collect();
+    if !preferred.is_empty() {
+        return preferred
+            .into_iter()
+            .find(|name| !unavailable.contains(name))
+            .ok_or_else(|| ConversionError::NoConverterAvailable { platform: platform.to_string() });
+    }
+
+    data.converter_names()
+        .into_iter()
+        .filter(|name| can_run(name))
+        .chain(available.iter().cloned())
+        .find(|name| !unavailable.contains(name))
+        .ok_or_else(|| ConversionError::NoConverterSelected { platform: platform.to_string() })
+}
+
+/// Check every configured converter against the registry and describe the converter used instead of
+/// the ones it cannot run. Returns a warning for each mismatch, without changing the config.
+pub fn validate_sources(state: &SourcesConfig, link_converter: &LinkConverter) -> Vec<ConfigWarning> {
+    let mut warnings = Vec::new();
+
+    for source in &state.sources {
+        let platform = source.get_platform_name();
+        let available = link_converter.registry().available_converters(platform);
+        let can_run = |name: &str| available.iter().any(|a| a.eq_ignore_ascii_case(name));
+        let data = source.get_operations();
+        let fallback = resolve_converter(data, link_converter, platform).ok();
+
+        for name in data.converter_names().into_iter().filter(|name| !can_run(name)) {
+            warnings.push(ConfigWarning {
+                platform: platform.to_string(),
+                message: format!("Converter '{}' is not supported for {}", name, platform),
+                converter: Some(name),
+                fallback: None,
+            });
+        }
+
+        let preferred = data.preferred_converters();
+        let unsupported: Vec<String> = preferred.iter().filter(|name| !can_run(name)).cloned().collect();
+        for name in unsupported {
+            let message = match &fallback {
+                Some(fallback) => format!("Converter '{}' is not supported for {}, using '{}' instead", name, platform, fallback),
+                None => format!("Converter '{}' is not supported for {} and no working converter is available", name, platform),
+            };
+            warnings.push(ConfigWarning {
+                platform: platform.to_string(),
+                converter: Some(name),
+                fallback: fallback.clone(),
+                message,
+            });
+        }
+
+        if preferred.is_empty() {
+            let message = match &fallback {
+                Some(fallback) => format!("No converter was selected for {}, using '{}'", platform, fallback),
+                None => format!("No converters are available for {}", platform),
+            };
+            warnings.push(ConfigWarning {
+                platform: platform.to_string(),
+                converter: None,
+                fallback,
+                message,
+            });
+        }
+    }
+
+    warnings
+}
+
+/// Point the fediverse converter at the instances from the user's settings
+pub fn apply_fediverse_settings(settings: &FediverseSettings, link_converter: &LinkConverter) -> Result<(), String> {
+    link_converter.registry().fediverse().configure(
+        &settings.instances,
+        &settings.home_instance,
+        &settings.embed_frontend,
+    )
+}
+
+/// Switch off the normalisation rules the user disabled
+pub fn apply_normalize_settings(settings: &NormalizeSettings, link_converter: &LinkConverter) {
+    link_converter.registry().normalizer().configure(&settings.disabled_rules);
+}
+
+/// Point every privacy frontend at the instances configured for its platform
+pub fn apply_privacy_settings(settings: &BTreeMap<String, PrivacyFrontendSettings>, link_converter: &LinkConverter) {
+    for frontend in link_converter.registry().privacy_frontends() {
+        let platform_settings = settings.get(frontend.platform_name()).cloned().unwrap_or_default();
+        frontend.configure(&platform_settings.instances, platform_settings.selection);
+    }
+}
+
+/// Register the self-hosted frontends of every platform, returning the entries that were skipped
+pub fn apply_custom_frontends(state: &SourcesConfig, link_converter: &LinkConverter) -> Vec<String> {
     state.sources
         .iter()
-        .find_map(|source| source.try_convert_link(link_converter, url))
-}
\ No newline at end of file
+        .filter_map(|source| {
+            link_converter
+                .registry()
+                .configure_custom_frontends(source.get_platform_name(), source.get_operations().custom_frontends())
+        })
+        .flatten()
+        .collect()
+}
+
+/// Apply the query parameter policy of the platform the URL belongs to, even if its conversion is disabled
+pub fn clean_query_params(state: &SourcesConfig, link_converter: &LinkConverter, url: &str) -> String {
+    state.sources
+        .iter()
+        .find(|source| link_converter.registry().recognizes(url, source.get_platform_name()))
+        .map(|source| source.get_operations().query_policy().apply(url))
+        .unwrap_or_else(|| url.to_string())
+}
+
+/// Recover the link hidden behind redirect wrappers, if unwrapping is enabled
+fn unwrap_redirects(state: &SourcesConfig, link_converter: &LinkConverter, url: &str) -> Option<String> {
+    if !state.clipboard.unwrap_redirects {
+        return None;
+    }
+    link_converter.unwrapper().unwrap(url)
+}
+
+/// Apply the generic ClearURLs rules, if they are enabled
+fn clear_tracking(state: &SourcesConfig, link_converter: &LinkConverter, url: &str) -> Option<String> {
+    if !state.clear_urls.enabled {
+        return None;
+    }
+    link_converter.clear_urls().clean(url)
+}
+
+/// Run a link operation on the cleaned URL, carrying the surviving query parameters over to its result.
+/// Redirect wrappers and generic tracking parameters are removed before the platform policy is applied.
+/// If the operation does not apply, the cleaned URL is returned when any of these steps changed anything.
+fn with_cleaned_query<F>(state: &SourcesConfig, link_converter: &LinkConverter, url: &str, operation: F) -> Option<String>
+where
+    F: Fn(&str) -> Option<String>,
+{
+    let stripped = strip_wrappers_and_tracking(state, link_converter, url);
+    let cleaned = clean_query_params(state, link_converter, &stripped);
+
+    match operation(&cleaned) {
+        Some(result) => Some(append_query(&result, carried_query(state, link_converter, &cleaned).as_deref())),
+        None if cleaned != url => Some(cleaned),
+        None => None,
+    }
+}
+
+/// Query of a cleaned URL that carries over to its converted link, leaving out the parameters
+/// the platform's conversion already moves into the link
+fn carried_query(state: &SourcesConfig, link_converter: &LinkConverter, cleaned: &str) -> Option<String> {
+    let content_params = state.sources
+        .iter()
+        .find(|source| link_converter.registry().recognizes(cleaned, source.get_platform_name()))
+        .map(|source| link_converter.registry().content_params(source.get_platform_name()))
+        .unwrap_or_default();
+
+    let carried = filter_query(cleaned, |name| !content_params.contains(&name));
+    query_string(&carried).map(String::from)
+}
+
+/// Remove redirect wrappers, AMP and mobile variants and generic tracking parameters, the steps that
+/// apply to every link, so platforms see the canonical desktop link
+fn strip_wrappers_and_tracking(state: &SourcesConfig, link_converter: &LinkConverter, url: &str) -> String {
+    let url = unwrap_redirects(state, link_converter, url).unwrap_or_else(|| url.to_string());
+    // Normalised links may belong to no platform, so they lose the tracking parameters all platforms strip
+    let url = match link_converter.registry().normalizer().normalize(&url) {
+        Some(normalized) => QueryPolicy::default().apply(&normalized),
+        None => url,
+    };
+    clear_tracking(state, link_converter, &url).unwrap_or(url)
+}
+
+/// Try to convert link using any available platform
+pub fn try_convert_with_all_platforms(state: &SourcesConfig, link_converter: &LinkConverter, url: &str) -> Option<String> {
+    with_cleaned_query(state, link_converter, url, |cleaned| {
+        convert_with_all_platforms(state, link_converter, cleaned).ok().map(|conversion| conversion.url)
+    })
+}
+
+/// Convert a link using any available platform like `try_convert_with_all_platforms`, explaining why
+/// the link was left alone when it is not converted. Links that are only unwrapped or lose tracking
+/// parameters are replaced on the clipboard too, so they come back as a `cleaned_only` conversion.
+pub fn explain_conversion_with_all_platforms(
+    state: &SourcesConfig,
+    link_converter: &LinkConverter,
+    url: &str,
+) -> Result<Conversion, ConversionError> {
+    let stripped = strip_wrappers_and_tracking(state, link_converter, url);
+    let cleaned = clean_query_params(state, link_converter, &stripped);
+
+    match convert_with_all_platforms(state, link_converter, &cleaned) {
+        Ok(mut conversion) => {
+            conversion.original = url.to_string();
+            conversion.url = append_query(&conversion.url, carried_query(state, link_converter, &cleaned).as_deref());
+            Ok(conversion)
+        }
+        Err(_) if cleaned != url => {
+            let platform = state.sources
+                .iter()
+                .map(|source| source.get_platform_name())
+                .find(|platform| link_converter.registry().recognizes(&cleaned, platform));
+
+            Ok(Conversion {
+                platform: platform.unwrap_or_default().to_string(),
+                converter: String::new(),
+                ids: platform.map(|platform| link_converter.registry().extract_ids(&cleaned, platform)).unwrap_or_default(),
+                original: url.to_string(),
+                url: cleaned,
+                cleaned_only: true,
+            })
+        }
+        Err(e) => Err(e),
+    }
+}
+
+fn convert_with_all_platforms(state: &SourcesConfig, link_converter: &LinkConverter, url: &str) -> Result<Conversion, ConversionError> {
+    // The first platform that recognises the link explains why it was left alone
+    let mut error = ConversionError::NotSupported;
+    for source in &state.sources {
+        match source.try_convert_link(link_converter, url) {
+            Ok(conversion) => return Ok(conversion),
+            Err(e) if !error.is_recognized() => error = e,
+            Err(_) => {}
+        }
+    }
+
+    // Links already on another frontend are moved to the selected one
+    if state.clipboard.retarget {
+        if let Some(conversion) = state.sources.iter().find_map(|source| source.try_retarget_link(link_converter, url)) {
+            return Ok(conversion);
+        }
+    }
+
+    Err(error)
+}
+
+/// Try to convert a frontend link back to its canonical URL using any enabled platform
+pub fn try_canonicalize_with_all_platforms(state: &SourcesConfig, link_converter: &LinkConverter, url: &str) -> Option<String> {
+    with_cleaned_query(state, link_converter, url, |cleaned| {
+        state.sources
+            .iter()
+            .find_map(|source| source.try_canonicalize_link(link_converter, cleaned))
+    })
+}
+
+/// Apply a link operation to every link found inside free-form text, leaving the rest untouched
+fn replace_links_in_text<F>(link_converter: &LinkConverter, text: &str, operation: F) -> Option<String>
+where
+    F: Fn(&str) -> Option<String>,
+{
+    let registry = link_converter.registry();
+    let known_platform = |link: &str| registry.platforms().iter().any(|platform| registry.recognizes(link, platform));
+
+    link_converter
+        .scanner()
+        .replace_links(text, |link| {
+            // Words like node.js/docs look like links without a scheme, so those must belong to a platform
+            if link.contains("://") || known_platform(link) {
+                operation(link)
+            } else {
+                None
+            }
+        })
+        .or_else(|| {
+            // Other links without a scheme are only recognised when they are the whole text
+            let link = text.trim();
+            if link.is_empty() || link.contains(char::is_whitespace) {
+                return None;
+            }
+            let start = text.len() - text.trim_start().len();
+            operation(link).map(|converted| format!("{}{}{}", &text[..start], converted, &text[start + link.len()..]))
+        })
+}
+
+/// Convert every supported link found inside free-form text, leaving the rest untouched
+pub fn try_convert_text_with_all_platforms(state: &SourcesConfig, link_converter: &LinkConverter, text: &str) -> Option<String> {
+    replace_links_in_text(link_converter, text, |url| {
+        try_convert_with_all_platforms(state, link_converter, url)
+    })
+}
+
+/// Canonicalize every known frontend link found inside free-form text
+pub fn try_canonicalize_text_with_all_platforms(state: &SourcesConfig, link_converter: &LinkConverter, text: &str) -> Option<String> {
+    replace_links_in_text(link_converter, text, |url| {
+        try_canonicalize_with_all_platforms(state, link_converter, url)
+    })
+}
+
+/// Process new clipboard content according to the clipboard settings.
+/// Returns None if nothing changed, so the clipboard is not rewritten to itself.
+pub fn convert_clipboard_content(state: &SourcesConfig, link_converter: &LinkConverter, content: &str) -> Option<String> {
+    let settings = &state.clipboard;
+
+    let converted = match (settings.scan_text, settings.canonicalize) {
+        (true, true) => try_canonicalize_text_with_all_platforms(state, link_converter, content),
+        (true, false) => try_convert_text_with_all_platforms(state, link_converter, content),
+        (false, true) => try_canonicalize_with_all_platforms(state, link_converter, content),
+        (false, false) => try_convert_with_all_platforms(state, link_converter, content),
+    };
+    converted.filter(|converted| converted != content)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::app_config::{PlatformDefaults, TikTokConverters, TwitterConverters};
+    use crate::services::clear_urls::ClearUrlsRules;
+
+    #[test]
+    fn test_convert_text_mixed_platforms() {
+        let state = SourcesConfig::default();
+        let link_converter = LinkConverter::new();
+        let text = "lol https://x.com/user/status/123 and\nhttps://www.tiktok.com/@user/video/456, bye";
+
+        let result = try_convert_text_with_all_platforms(&state, &link_converter, text);
+
+        assert_eq!(
+            result,
+            Some("lol https://vxtwitter.com/user/status/123 and\nhttps://tfxktok.com/@user/video/456, bye".to_string())
+        );
+    }
+
+    #[test]
+    fn test_convert_text_without_supported_links() {
+        let state = SourcesConfig::default();
+        let link_converter = LinkConverter::new();
+
+        assert!(try_convert_text_with_all_platforms(&state, &link_converter, "see https://example.com/a").is_none());
+        assert!(try_convert_text_with_all_platforms(&state, &link_converter, "example.com/a hi").is_none());
+    }
+
+    #[test]
+    fn test_canonicalize_clipboard_mode() {
+        let mut state = SourcesConfig::default();
+        let link_converter = LinkConverter::new();
+        let text = "from https://fxtwitter.com/user/status/1 and https://ddinstagram.com/u/reel/ABC";
+
+        // Without canonicalize the links are moved to the selected frontends instead, if retargeting is on
+        assert!(convert_clipboard_content(&state, &link_converter, text).is_none());
+        state.clipboard.retarget = true;
+        assert_eq!(
+            convert_clipboard_content(&state, &link_converter, text),
+            Some("from https://vxtwitter.com/user/status/1 and https://kkinstagram.com/u/reel/ABC".to_string())
+        );
+
+        state.clipboard.canonicalize = true;
+        assert_eq!(
+            convert_clipboard_content(&state, &link_converter, text),
+            Some("from https://x.com/user/status/1 and https://www.instagram.com/u/reel/ABC".to_string())
+        );
+
+        // Disabled platforms are left alone
+        with_platform_data(&mut state, Platform::Instagram, |data| data.set_enabled(false));
+        assert_eq!(
+            convert_clipboard_content(&state, &link_converter, text),
+            Some("from https://x.com/user/status/1 and https://ddinstagram.com/u/reel/ABC".to_string())
+        );
+    }
+
+    #[test]
+    fn test_retarget_other_frontends() {
+        let mut state = SourcesConfig::default();
+        let link_converter = LinkConverter::new();
+        assert!(!state.clipboard.retarget);
+        state.clipboard.retarget = true;
+
+        with_platform_data(&mut state, Platform::Twitter, |data| data.set_converter_by_name("fixupx"));
+
+        let result = try_convert_with_all_platforms(&state, &link_converter, "https://vxtwitter.com/user/status/1");
+        assert_eq!(result, Some("https://fixupx.com/user/status/1".to_string()));
+
+        // Links on the selected frontend are left alone, including its modes
+        assert!(try_convert_with_all_platforms(&state, &link_converter, "https://fixupx.com/user/status/1").is_none());
+        with_platform_data(&mut state, Platform::Twitter, |data| data.set_converter_by_name("fxtwitter"));
+        assert!(try_convert_with_all_platforms(&state, &link_converter, "https://d.fxtwitter.com/user/status/1").is_none());
+
+        // Frontend links are never moved back to the platform itself
+        assert!(try_convert_with_all_platforms(&state, &link_converter, "https://fxbsky.app/profile/a.bsky.social/post/3k").is_none());
+
+        state.clipboard.retarget = false;
+        assert!(try_convert_with_all_platforms(&state, &link_converter, "https://vxtwitter.com/user/status/1").is_none());
+    }
+
+    #[test]
+    fn test_query_params_cleaned_and_kept() {
+        let mut state = SourcesConfig::default();
+        let link_converter = LinkConverter::new();
+
+        // Tracking parameters are dropped, legitimate ones survive conversion
+        let result = try_convert_with_all_platforms(&state, &link_converter, "https://x.com/user/status/1?s=20&t=abc&lang=en");
+        assert_eq!(result, Some("https://vxtwitter.com/user/status/1?lang=en".to_string()));
+
+        let result = try_convert_with_all_platforms(&state, &link_converter, "https://www.tiktok.com/@user/video/2?_r=1&_t=xyz");
+        assert_eq!(result, Some("https://tfxktok.com/@user/video/2".to_string()));
+
+        // Links are still cleaned when the platform's converter is disabled
+        with_platform_data(&mut state, Platform::Instagram, |data| data.set_enabled(false));
+        let result = try_convert_with_all_platforms(&state, &link_converter, "https://www.instagram.com/user/reel/ABC/?igsh=xyz&utm_source=ig");
+        assert_eq!(result, Some("https://www.instagram.com/user/reel/ABC/".to_string()));
+
+        // Nothing to do for clean links of disabled platforms or unknown links
+        assert!(try_convert_with_all_platforms(&state, &link_converter, "https://www.instagram.com/user/reel/ABC/").is_none());
+        assert!(try_convert_with_all_platforms(&state, &link_converter, "https://example.com/?utm_source=x").is_none());
+    }
+
+    #[test]
+    fn test_keep_list_policy() {
+        let mut state = SourcesConfig::default();
+        let link_converter = LinkConverter::new();
+
+        with_platform_data(&mut state, Platform::Twitter, |data| {
+            data.set_query_policy(QueryPolicy::Keep(vec!["lang".to_string()]))
+        });
+
+        let result = try_convert_with_all_platforms(&state, &link_converter, "https://x.com/user/status/1?foo=1&lang=en&bar=2");
+        assert_eq!(result, Some("https://vxtwitter.com/user/status/1?lang=en".to_string()));
+    }
+
+    #[test]
+    fn test_parse_platform() {
+        assert_eq!(parse_platform("Reddit"), Some(Platform::Reddit));
+        assert_eq!(parse_platform("threads"), Some(Platform::Threads));
+        assert_eq!(parse_platform("twitter"), Some(Platform::Twitter));
+        assert_eq!(parse_platform("myspace"), None);
+    }
+
+    #[test]
+    fn test_custom_converter_selection() {
+        let mut state = SourcesConfig::default();
+
+        with_platform_data(&mut state, Platform::Twitter, |data| {
+            assert!(!data.set_converter_by_name("myembed"));
+            assert!(data.set_custom_converter("myembed"));
+            assert_eq!(data.preferred_converters()[0], "myembed");
+        });
+
+        // Custom selections survive a round trip through the config file format
+        let yaml = serde_yaml::to_string(&state).unwrap();
+        let reloaded: SourcesConfig = serde_yaml::from_str(&yaml).unwrap();
+        assert_eq!(
+            reloaded.sources[0].get_operations().preferred_converters(),
+            state.sources[0].get_operations().preferred_converters()
+        );
+    }
+
+    #[test]
+    fn test_unavailable_converter_falls_through() {
+        let mut state = SourcesConfig::default();
+        let link_converter = LinkConverter::new();
+        let url = "https://x.com/user/status/1";
+
+        with_platform_data(&mut state, Platform::Twitter, |data| {
+            assert!(data.set_converter_available("vxtwitter", false));
+            assert_eq!(resolve_converter(data, &link_converter, "twitter"), Ok("fxtwitter".to_string()));
+        });
+        assert_eq!(
+            try_convert_with_all_platforms(&state, &link_converter, url),
+            Some("https://fxtwitter.com/user/status/1".to_string())
+        );
+
+        with_platform_data(&mut state, Platform::Twitter, |data| data.set_converter_available("fxtwitter", false));
+        assert_eq!(
+            explain_conversion_with_all_platforms(&state, &link_converter, url),
+            Err(ConversionError::NoConverterAvailable { platform: "twitter".to_string() })
+        );
+
+        with_platform_data(&mut state, Platform::Twitter, |data| data.set_converter_available("vxtwitter", true));
+        assert_eq!(
+            try_convert_with_all_platforms(&state, &link_converter, url),
+            Some("https://vxtwitter.com/user/status/1".to_string())
+        );
+    }
+
+    #[test]
+    fn test_missing_query_policy_uses_platform_default() {
+        let mut state = SourcesConfig::default();
+        let link_converter = LinkConverter::new();
+
+        // Config files from before query policies have no query_policy field
+        let source: PlatformSource = serde_yaml::from_str(
+            "platform: twitter\ndata:\n  enabled: true\n  converters: [vxtwitter]\n  selected: vxtwitter\n",
+        )
+        .unwrap();
+        assert_eq!(source.get_operations().query_policy(), &TwitterConverters::default_query_policy());
+
+        state.sources.retain(|s| s.get_platform_type() != Platform::Twitter);
+        state.sources.push(source);
+        assert_eq!(
+            try_convert_with_all_platforms(&state, &link_converter, "https://x.com/user/status/1?s=20&t=abc"),
+            Some("https://vxtwitter.com/user/status/1".to_string())
+        );
+    }
+
+    #[test]
+    fn test_preference_list() {
+        let mut state = SourcesConfig::default();
+
+        with_platform_data(&mut state, Platform::Twitter, |data| {
+            assert!(data.set_converter_by_name("fixupx"));
+            assert_eq!(data.preferred_converters(), vec!["fixupx", "vxtwitter", "fxtwitter"]);
+            assert!(data.set_preferred_converters(&["fxtwitter".to_string(), "fxtwitter".to_string()]));
+            assert_eq!(data.preferred_converters(), vec!["fxtwitter"]);
+        });
+
+        // Config files from before preference lists have a single selected converter
+        let yaml = serde_yaml::to_string(&state).unwrap().replace("preferred:\n    - fxtwitter", "selected: fixvx");
+        let reloaded: SourcesConfig = serde_yaml::from_str(&yaml).unwrap();
+        assert_eq!(reloaded.sources[0].get_operations().preferred_converters(), vec!["fixvx"]);
+
+        let yaml = yaml.replace("selected: fixvx", "selected: null");
+        let reloaded: SourcesConfig = serde_yaml::from_str(&yaml).unwrap();
+        assert!(reloaded.sources[0].get_operations().preferred_converters().is_empty());
+    }
+
+    #[test]
+    fn test_missing_sources_are_added() {
+        let mut state = SourcesConfig::default();
+        state.sources.retain(|source| source.get_platform_type() != Platform::Reddit);
+
+        state.add_missing_sources();
+
+        assert!(state.sources.iter().any(|source| source.get_platform_type() == Platform::Reddit));
+        assert_eq!(state.sources.len(), SourcesConfig::default().sources.len());
+    }
+
+    #[test]
+    fn test_missing_converters_are_added() {
+        let mut state = SourcesConfig::default();
+        with_platform_data(&mut state, Platform::Twitter, |data| {
+            assert!(data.set_custom_converter("nitter"));
+        });
+        let twitter = state.sources.iter_mut().find(|s| s.get_platform_type() == Platform::Twitter).unwrap();
+        let yaml = serde_yaml::to_string(&*twitter).unwrap().replace("  - nitter\n", "");
+        *twitter = serde_yaml::from_str(&yaml).unwrap();
+        assert!(!twitter.get_operations().converter_names().contains(&"nitter".to_string()));
+
+        state.add_missing_converters();
+
+        let twitter = state.sources.iter().find(|s| s.get_platform_type() == Platform::Twitter).unwrap();
+        let names = twitter.get_operations().converter_names();
+        assert!(names.contains(&"nitter".to_string()));
+        assert_eq!(names.len(), names.iter().collect::<std::collections::BTreeSet<_>>().len());
+    }
+
+    #[test]
+    fn test_convert_text_bare_link() {
+        let state = SourcesConfig::default();
+        let link_converter = LinkConverter::new();
+
+        let result = try_convert_text_with_all_platforms(&state, &link_converter, "x.com/user/status/1\n");
+        assert_eq!(result, Some("https://vxtwitter.com/user/status/1\n".to_string()));
+
+        let result = try_convert_text_with_all_platforms(&state, &link_converter, "  www.google.com/amp/s/example.com/story?utm_source=x ");
+        assert_eq!(result, Some("  https://example.com/story ".to_string()));
+    }
+
+    #[test]
+    fn test_convert_text_links_without_scheme() {
+        let state = SourcesConfig::default();
+        let link_converter = LinkConverter::new();
+
+        let text = "see x.com/user/status/1 and (tiktok.com/@user/video/456), not node.js/docs";
+        assert_eq!(
+            try_convert_text_with_all_platforms(&state, &link_converter, text),
+            Some("see https://vxtwitter.com/user/status/1 and (https://tfxktok.com/@user/video/456), not node.js/docs".to_string())
+        );
+        assert!(try_convert_text_with_all_platforms(&state, &link_converter, "read example.com/page?utm_source=x now").is_none());
+    }
+
+    #[test]
+    fn test_validate_sources_falls_back() {
+        let mut state = SourcesConfig::default();
+        let link_converter = LinkConverter::new();
+
+        assert!(validate_sources(&state, &link_converter).is_empty());
+
+        with_platform_data(&mut state, Platform::Tiktok, |data| {
+            assert!(data.set_custom_converter("tntok"));
+        });
+        let tiktok = state.sources.iter_mut().find(|s| s.get_platform_type() == Platform::Tiktok).unwrap();
+        if let PlatformSource::Tiktok(data) = tiktok {
+            data.converters.insert(0, TikTokConverters::Tntok);
+        }
+
+        let warnings = validate_sources(&state, &link_converter);
+
+        assert_eq!(warnings.len(), 2);
+        assert_eq!(warnings[0].converter.as_deref(), Some("tntok"));
+        assert_eq!(warnings[0].fallback, None);
+        assert_eq!(warnings[1].fallback.as_deref(), Some("tfxktok"));
+        assert_eq!(
+            try_convert_with_all_platforms(&state, &link_converter, "https://www.tiktok.com/@user/video/123"),
+            Some("https://tfxktok.com/@user/video/123".to_string())
+        );
+
+        // The config is left alone, so the converter is used again once it can run
+        let tiktok = state.sources.iter().find(|s| s.get_platform_type() == Platform::Tiktok).unwrap();
+        assert_eq!(tiktok.get_operations().preferred_converters(), vec!["tntok", "tfxktok"]);
+        assert_eq!(tiktok.get_operations().converter_names(), vec!["tntok", "tfxktok", "tiktokez", "proxitok"]);
+    }
+
+    #[test]
+    fn test_fediverse_settings() {
+        let mut state = SourcesConfig::default();
+        let link_converter = LinkConverter::new();
+        let url = "https://mastodon.social/@user/111222333";
+
+        with_platform_data(&mut state, Platform::Fediverse, |data| data.set_enabled(true));
+        assert!(try_convert_with_all_platforms(&state, &link_converter, url).is_none());
+
+        state.fediverse.instances = vec!["mastodon.social".to_string()];
+        state.fediverse.home_instance = "hachyderm.io".to_string();
+        apply_fediverse_settings(&state.fediverse, &link_converter).unwrap();
+
+        assert_eq!(
+            try_convert_with_all_platforms(&state, &link_converter, url),
+            Some("https://hachyderm.io/authorize_interaction?uri=https%3A%2F%2Fmastodon.social%2F%40user%2F111222333".to_string())
+        );
+    }
+
+    #[test]
+    fn test_query_identified_links() {
+        let state = SourcesConfig::default();
+        let link_converter = LinkConverter::new();
+
+        assert_eq!(
+            try_convert_with_all_platforms(&state, &link_converter, "https://www.pixiv.net/member_illust.php?mode=medium&illust_id=123456&page=1"),
+            Some("https://phixiv.net/artworks/123456/2".to_string())
+        );
+    }
+
+    #[test]
+    fn test_youtube_links() {
+        let mut state = SourcesConfig::default();
+        let link_converter = LinkConverter::new();
+
+        assert_eq!(
+            try_convert_with_all_platforms(&state, &link_converter, "https://youtube.com/shorts/dQw4w9WgXcQ?si=abc&feature=share"),
+            Some("https://www.youtube.com/watch?v=dQw4w9WgXcQ".to_string())
+        );
+        // Already a watch link, so only the share tracking is dropped
+        assert_eq!(
+            try_convert_with_all_platforms(&state, &link_converter, "https://www.youtube.com/watch?v=dQw4w9WgXcQ&si=abc&t=42"),
+            Some("https://www.youtube.com/watch?v=dQw4w9WgXcQ&t=42".to_string())
+        );
+
+        // The video id and timestamp are part of the short link, not carried over as leftovers
+        with_platform_data(&mut state, Platform::Youtube, |data| {
+            assert!(data.set_converter_by_name("short"));
+        });
+        assert_eq!(
+            try_convert_with_all_platforms(&state, &link_converter, "https://www.youtube.com/watch?v=dQw4w9WgXcQ&t=42&si=abc"),
+            Some("https://youtu.be/dQw4w9WgXcQ?t=42".to_string())
+        );
+        assert_eq!(
+            try_convert_with_all_platforms(&state, &link_converter, "https://www.youtube.com/watch?v=dQw4w9WgXcQ&list=PL1"),
+            Some("https://youtu.be/dQw4w9WgXcQ?list=PL1".to_string())
+        );
+    }
+
+    #[test]
+    fn test_music_links() {
+        let state = SourcesConfig::default();
+        let link_converter = LinkConverter::new();
+
+        assert_eq!(
+            try_convert_with_all_platforms(&state, &link_converter, "https://open.spotify.com/track/4cOdK2wGLETKBW3PvgPWqT?si=abc"),
+            Some("https://song.link/https://open.spotify.com/track/4cOdK2wGLETKBW3PvgPWqT".to_string())
+        );
+        // Universal links are left alone, including the parameters of the link they wrap
+        assert!(try_convert_with_all_platforms(
+            &state,
+            &link_converter,
+            "https://song.link/https://music.apple.com/us/album/a/1440857781?i=1440857795"
+        ).is_none());
+    }
+
+    #[test]
+    fn test_wrapped_links_are_unwrapped_first() {
+        let mut state = SourcesConfig::default();
+        let link_converter = LinkConverter::new();
+        let wrapped = "https://www.google.com/url?q=https%3A%2F%2Fx.com%2Fuser%2Fstatus%2F123%3Fs%3D20&sa=D";
+
+        assert_eq!(
+            try_convert_with_all_platforms(&state, &link_converter, wrapped),
+            Some("https://vxtwitter.com/user/status/123".to_string())
+        );
+        // Unsupported links are still unwrapped
+        assert_eq!(
+            try_convert_with_all_platforms(&state, &link_converter, "https://l.facebook.com/l.php?u=https%3A%2F%2Fexample.com%2Fa&h=x"),
+            Some("https://example.com/a".to_string())
+        );
+
+        state.clipboard.unwrap_redirects = false;
+        assert!(try_convert_with_all_platforms(&state, &link_converter, wrapped).is_none());
+    }
+
+    #[test]
+    fn test_clear_urls_rules() {
+        let mut state = SourcesConfig::default();
+        let mut link_converter = LinkConverter::new();
+        let (rules, _) = ClearUrlsRules::from_json(r#"{
+            "providers": {
+                "globalRules": { "urlPattern": ".*", "rules": ["fbclid", "s"] },
+                "shop": { "urlPattern": "^https?://shop\\.example\\.com", "rules": ["ref"] }
+            }
+        }"#).unwrap();
+        link_converter.set_clear_urls(rules);
+
+        assert_eq!(
+            try_convert_with_all_platforms(&state, &link_converter, "https://shop.example.com/item?id=1&ref=abc&fbclid=x"),
+            Some("https://shop.example.com/item?id=1".to_string())
+        );
+        assert_eq!(
+            try_convert_with_all_platforms(&state, &link_converter, "https://x.com/user/status/123?s=20&fbclid=x"),
+            Some("https://vxtwitter.com/user/status/123".to_string())
+        );
+
+        state.clear_urls.enabled = false;
+        assert!(try_convert_with_all_platforms(&state, &link_converter, "https://shop.example.com/item?id=1&ref=abc").is_none());
+    }
+
+    #[test]
+    fn test_amp_and_mobile_links() {
+        let mut state = SourcesConfig::default();
+        let link_converter = LinkConverter::new();
+
+        assert_eq!(
+            try_convert_with_all_platforms(&state, &link_converter, "https://www.google.com/amp/s/www.example.com/story?id=4&utm_source=x"),
+            Some("https://www.example.com/story?id=4".to_string())
+        );
+        // Video links on the mobile site are handled by the YouTube platform
+        assert_eq!(
+            try_convert_with_all_platforms(&state, &link_converter, "https://m.youtube.com/watch?v=dQw4w9WgXcQ"),
+            Some("https://www.youtube.com/watch?v=dQw4w9WgXcQ".to_string())
+        );
+        assert_eq!(
+            try_convert_with_all_platforms(&state, &link_converter, "https://m.youtube.com/shorts/dQw4w9WgXcQ"),
+            try_convert_with_all_platforms(&state, &link_converter, "https://www.youtube.com/shorts/dQw4w9WgXcQ")
+        );
+        // Normalised links go on to their platform's converter
+        assert_eq!(
+            try_convert_with_all_platforms(&state, &link_converter, "https://www.google.com/amp/s/x.com/user/status/1?s=20"),
+            Some("https://vxtwitter.com/user/status/1".to_string())
+        );
+        let conversion = explain_conversion_with_all_platforms(&state, &link_converter, "https://www.google.com/amp/s/x.com/user/status/1").unwrap();
+        assert_eq!(conversion.platform, "twitter");
+        assert!(!conversion.cleaned_only);
+
+        state.normalize.disabled_rules = vec!["wikimedia-mobile".to_string()];
+        apply_normalize_settings(&state.normalize, &link_converter);
+        assert!(try_convert_with_all_platforms(&state, &link_converter, "https://en.m.wikipedia.org/wiki/Rust").is_none());
+    }
+
+    #[test]
+    fn test_privacy_frontends() {
+        let mut state = SourcesConfig::default();
+        let link_converter = LinkConverter::new();
+        state.privacy.insert("youtube".to_string(), PrivacyFrontendSettings {
+            instances: vec!["yt.example.org".to_string()],
+            ..Default::default()
+        });
+        apply_privacy_settings(&state.privacy, &link_converter);
+
+        with_platform_data(&mut state, Platform::Youtube, |data| data.set_converter_by_name("invidious"));
+        assert_eq!(
+            try_convert_with_all_platforms(&state, &link_converter, "https://youtube.com/shorts/dQw4w9WgXcQ?si=abc"),
+            Some("https://yt.example.org/shorts/dQw4w9WgXcQ".to_string())
+        );
+        assert_eq!(
+            try_convert_with_all_platforms(&state, &link_converter, "https://www.youtube.com/watch?v=dQw4w9WgXcQ&si=abc&t=42"),
+            Some("https://yt.example.org/watch?v=dQw4w9WgXcQ&t=42".to_string())
+        );
+        // Links already on an instance are not moved to another one
+        assert!(try_convert_with_all_platforms(&state, &link_converter, "https://yt.example.org/watch?v=dQw4w9WgXcQ").is_none());
+
+        // Links on the privacy frontend can be turned back into original links
+        state.clipboard.canonicalize = true;
+        assert_eq!(
+            convert_clipboard_content(&state, &link_converter, "https://yt.example.org/watch?v=dQw4w9WgXcQ"),
+            Some("https://www.youtube.com/watch?v=dQw4w9WgXcQ".to_string())
+        );
+    }
+
+    #[test]
+    fn test_passthrough_converter() {
+        let mut state = SourcesConfig::default();
+        let link_converter = LinkConverter::new();
+        let url = "https://bsky.app/profile/user.bsky.social/post/123";
+
+        // The default Bluesky converter keeps bsky.app links where they are
+        assert!(link_converter.registry().is_passthrough("bluesky", "bsky"));
+        assert!(!link_converter.registry().is_passthrough("bluesky", "fxbsky"));
+        assert!(convert_clipboard_content(&state, &link_converter, url).is_none());
+        assert!(convert_clipboard_content(&state, &link_converter, &format!("look: {}", url)).is_none());
+
+        // Links it can still improve are converted
+        assert_eq!(
+            convert_clipboard_content(&state, &link_converter, "at://user.bsky.social/app.bsky.feed.post/123"),
+            Some(url.to_string())
+        );
+        assert_eq!(
+            convert_clipboard_content(&state, &link_converter, "https://go.bsky.app/AbC123"),
+            Some("https://bsky.app/starter-pack-short/AbC123".to_string())
+        );
+
+        state.clipboard.scan_text = false;
+        assert!(convert_clipboard_content(&state, &link_converter, url).is_none());
+    }
+
+    #[test]
+    fn test_converter_options() {
+        let mut state = SourcesConfig::default();
+        let link_converter = LinkConverter::new();
+        state.clipboard.retarget = true;
+
+        with_platform_data(&mut state, Platform::Twitter, |data| {
+            data.set_converter_by_name("fxtwitter");
+            data.set_options(ConverterOptions { direct_media: true, ..Default::default() });
+        });
+        assert_eq!(
+            try_convert_with_all_platforms(&state, &link_converter, "https://x.com/user/status/1?s=20"),
+            Some("https://d.fxtwitter.com/user/status/1".to_string())
+        );
+        // Links on the frontend without the chosen mode are moved to it
+        assert_eq!(
+            try_convert_with_all_platforms(&state, &link_converter, "https://fxtwitter.com/user/status/1"),
+            Some("https://d.fxtwitter.com/user/status/1".to_string())
+        );
+        assert!(try_convert_with_all_platforms(&state, &link_converter, "https://d.fxtwitter.com/user/status/1").is_none());
+    }
+
+    #[test]
+    fn test_custom_frontends() {
+        let mut state = SourcesConfig::default();
+        let link_converter = LinkConverter::new();
+
+        with_platform_data(&mut state, Platform::Twitter, |data| {
+            data.set_custom_frontends(vec![CustomFrontend {
+                name: "internal".to_string(),
+                domain: "fx.example.internal".to_string(),
+                template: "/{user}/status/{status}".to_string(),
+            }]);
+        });
+        assert!(apply_custom_frontends(&state, &link_converter).is_empty());
+        assert!(link_converter.registry().available_converters("twitter").contains(&"internal".to_string()));
+
+        with_platform_data(&mut state, Platform::Twitter, |data| data.set_custom_converter("internal"));
+        assert_eq!(
+            try_convert_with_all_platforms(&state, &link_converter, "https://x.com/user/status/1?s=20"),
+            Some("https://fx.example.internal/user/status/1".to_string())
+        );
+        assert!(try_convert_with_all_platforms(&state, &link_converter, "https://fx.example.internal/user/status/1").is_none());
+
+        // Privacy frontends keep their names
+        let nitter = CustomFrontend { name: "Nitter".to_string(), domain: "n.example.internal".to_string(), template: "/{user}".to_string() };
+        let errors = link_converter.registry().configure_custom_frontends("twitter", &[nitter]).unwrap();
+        assert_eq!(errors, vec!["Custom converter 'nitter' for twitter is already defined".to_string()]);
+    }
+
+    #[test]
+    fn test_explain_conversion() {
+        let mut state = SourcesConfig::default();
+        let link_converter = LinkConverter::new();
+
+        let conversion = explain_conversion_with_all_platforms(&state, &link_converter, "https://x.com/user/status/1?s=20").unwrap();
+        assert_eq!(conversion.platform, "twitter");
+        assert_eq!(conversion.converter, "vxtwitter");
+        assert_eq!(conversion.ids.get("user").map(String::as_str), Some("user"));
+        assert_eq!(conversion.ids.get("status").map(String::as_str), Some("1"));
+        assert_eq!(conversion.original, "https://x.com/user/status/1?s=20");
+        assert_eq!(conversion.url, "https://vxtwitter.com/user/status/1");
+
+        assert_eq!(
+            explain_conversion_with_all_platforms(&state, &link_converter, "https://bsky.app/profile/a.bsky.social/post/1"),
+            Err(ConversionError::IdentityConversion { platform: "bluesky".to_string(), converter: "bsky".to_string() })
+        );
+        assert_eq!(
+            explain_conversion_with_all_platforms(&state, &link_converter, "https://example.com/?utm_source=x"),
+            Err(ConversionError::NotSupported)
+        );
+
+        // Links the clipboard only unwraps are reported as cleaned, with the same result
+        let url = "https://www.google.com/url?q=https%3A%2F%2Fexample.com%2Fpage&sa=D";
+        let conversion = explain_conversion_with_all_platforms(&state, &link_converter, url).unwrap();
+        assert!(conversion.cleaned_only);
+        assert_eq!(conversion.platform, "");
+        assert_eq!(Some(conversion.url), try_convert_with_all_platforms(&state, &link_converter, url));
+
+        with_platform_data(&mut state, Platform::Reddit, |data| data.set_enabled(false));
+        assert_eq!(
+            explain_conversion_with_all_platforms(&state, &link_converter, "https://redd.it/abc123"),
+            Err(ConversionError::PlatformDisabled { platform: "reddit".to_string() })
+        );
+        let url = "https://www.reddit.com/r/rust/comments/abc123/title/?utm_source=share";
+        let conversion = explain_conversion_with_all_platforms(&state, &link_converter, url).unwrap();
+        assert!(conversion.cleaned_only);
+        assert_eq!(conversion.platform, "reddit");
+        assert_eq!(Some(conversion.url), try_convert_with_all_platforms(&state, &link_converter, url));
+
+        state.clipboard.retarget = false;
+        assert_eq!(
+            explain_conversion_with_all_platforms(&state, &link_converter, "https://fixupx.com/user/status/1"),
+            Err(ConversionError::AlreadyConverted { platform: "twitter".to_string(), converter: "vxtwitter".to_string() })
+        );
+
+        state.clipboard.retarget = true;
+        let conversion = explain_conversion_with_all_platforms(&state, &link_converter, "https://fixupx.com/user/status/1").unwrap();
+        assert_eq!(conversion.url, "https://vxtwitter.com/user/status/1");
+        assert_eq!(conversion.ids.get("status").map(String::as_str), Some("1"));
+    }
+}
@@ -0,0 +1,257 @@
+use crate::services::query_params::{decode_component, filter_query, param_name, split_url};
+use regex::{Regex, RegexBuilder};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+/// Redirections are followed at most this many times
+const MAX_REDIRECTIONS: usize = 5;
+
+/// A provider as written in ClearURLs' data.min.json. Referral marketing parameters are left alone.
+#[derive(Debug, Deserialize, Default)]
+#[serde(rename_all = "camelCase", default)]
+struct ProviderDefinition {
+    url_pattern: String,
+    complete_provider: bool,
+    rules: Vec<String>,
+    raw_rules: Vec<String>,
+    exceptions: Vec<String>,
+    redirections: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct RulesFile {
+    providers: BTreeMap<String, ProviderDefinition>,
+}
+
+struct Provider {
+    url_pattern: Regex,
+    /// Matches whole parameter names
+    rules: Vec<Regex>,
+    raw_rules: Vec<Regex>,
+    exceptions: Vec<Regex>,
+    redirections: Vec<Regex>,
+}
+
+/// Generic URL cleaning engine using the ClearURLs rule format
+#[derive(Default)]
+pub struct ClearUrlsRules {
+    providers: Vec<Provider>,
+}
+
+// ClearURLs patterns are case-insensitive JavaScript regexes
+fn compile(pattern: &str) -> Result<Regex, regex::Error> {
+    RegexBuilder::new(pattern).case_insensitive(true).build()
+}
+
+impl Provider {
+    fn compile(name: &str, definition: &ProviderDefinition) -> Result<Self, String> {
+        let compile_all = |patterns: &[String], anchored: bool| {
+            patterns
+                .iter()
+                .map(|p| match anchored {
+                    true => compile(&format!("^(?:{})$", p)),
+                    false => compile(p),
+                })
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|e| format!("Provider '{}': {}", name, e))
+        };
+
+        Ok(Self {
+            url_pattern: compile(&definition.url_pattern).map_err(|e| format!("Provider '{}': {}", name, e))?,
+            rules: compile_all(&definition.rules, true)?,
+            raw_rules: compile_all(&definition.raw_rules, false)?,
+            exceptions: compile_all(&definition.exceptions, false)?,
+            redirections: compile_all(&definition.redirections, false)?,
+        })
+    }
+
+    fn applies_to(&self, url: &str) -> bool {
+        self.url_pattern.is_match(url) && !self.exceptions.iter().any(|e| e.is_match(url))
+    }
+
+    fn redirect_target(&self, url: &str) -> Option<String> {
+        self.redirections
+            .iter()
+            .find_map(|r| r.captures(url)?.get(1))
+            .and_then(|target| decode_component(target.as_str()))
+    }
+
+    fn clean(&self, url: &str) -> String {
+        let mut cleaned = url.to_string();
+        for raw_rule in &self.raw_rules {
+            cleaned = raw_rule.replace_all(&cleaned, "").into_owned();
+        }
+
+        let is_tracking = |name: &str| self.rules.iter().any(|rule| rule.is_match(name));
+        let cleaned = filter_query(&cleaned, |name| !is_tracking(name));
+
+        // ClearURLs also strips tracking parameters written into the fragment
+        match split_url(&cleaned) {
+            (_, _, Some(fragment)) if fragment.contains('=') => {
+                let (before, _) = cleaned.split_once('#').unwrap();
+                let kept: Vec<&str> = fragment
+                    .split('&')
+                    .filter(|param| !param.is_empty() && !is_tracking(param_name(param)))
+                    .collect();
+                match kept.is_empty() {
+                    true => before.to_string(),
+                    false => format!("{}#{}", before, kept.join("&")),
+                }
+            }
+            _ => cleaned,
+        }
+    }
+}
+
+impl ClearUrlsRules {
+    /// Load rules from a ClearURLs data file. A missing file yields an empty rule set.
+    /// Providers whose patterns cannot be compiled are skipped and returned as errors.
+    pub fn from_file(path: &Path) -> Result<(Self, Vec<String>), String> {
+        if !path.exists() {
+            return Ok((Self::default(), Vec::new()));
+        }
+
+        let json = fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read ClearURLs rules: {}", e))?;
+        Self::from_json(&json)
+    }
+
+    pub fn from_json(json: &str) -> Result<(Self, Vec<String>), String> {
+        let file: RulesFile = serde_json::from_str(json)
+            .map_err(|e| format!("Invalid ClearURLs rules: {}", e))?;
+
+        let mut providers = Vec::new();
+        let mut errors = Vec::new();
+
+        for (name, definition) in &file.providers {
+            // Complete providers block whole sites, which a clipboard tool has no business doing
+            if definition.complete_provider {
+                continue;
+            }
+            match Provider::compile(name, definition) {
+                Ok(provider) => providers.push(provider),
+                Err(e) => errors.push(e),
+            }
+        }
+
+        Ok((Self { providers }, errors))
+    }
+
+    /// Number of providers that were loaded
+    pub fn len(&self) -> usize {
+        self.providers.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.providers.is_empty()
+    }
+
+    /// Clean a URL with every provider that applies to it, following redirections.
+    /// Returns None if nothing changed.
+    pub fn clean(&self, url: &str) -> Option<String> {
+        let mut current = url.to_string();
+
+        for _ in 0..MAX_REDIRECTIONS {
+            match self.providers.iter().filter(|p| p.applies_to(&current)).find_map(|p| p.redirect_target(&current)) {
+                Some(target) => current = target,
+                None => break,
+            }
+        }
+
+        for provider in &self.providers {
+            if provider.applies_to(&current) {
+                current = provider.clean(&current);
+            }
+        }
+
+        (current != url).then_some(current)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RULES: &str = r#"{
+        "providers": {
+            "globalRules": {
+                "urlPattern": ".*",
+                "completeProvider": false,
+                "rules": ["utm_[a-z]+", "fbclid", "gclid"],
+                "referralMarketing": [],
+                "rawRules": [],
+                "exceptions": ["^https?://[^/]*localhost"],
+                "redirections": [],
+                "forceRedirection": false
+            },
+            "amazon": {
+                "urlPattern": "^https?://(?:[a-z0-9-]+\\.)*?amazon(?:\\.[a-z]{2,}){1,}",
+                "rules": ["pd_rd_[a-z]*", "ref_?", "tag"],
+                "rawRules": ["/ref=[^/?]*"],
+                "exceptions": []
+            },
+            "example-redirect": {
+                "urlPattern": "^https?://out\\.example\\.com",
+                "redirections": ["^https?://out\\.example\\.com/go\\?to=([^&]*)"]
+            },
+            "blocker": {
+                "urlPattern": "^https?://ads\\.example\\.com",
+                "completeProvider": true
+            },
+            "broken": {
+                "urlPattern": "(?<=lookbehind)"
+            }
+        }
+    }"#;
+
+    #[test]
+    fn test_load_rules() {
+        let (rules, errors) = ClearUrlsRules::from_json(RULES).unwrap();
+
+        assert_eq!(rules.len(), 3);
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].contains("broken"));
+        assert!(ClearUrlsRules::from_json("{}").is_err());
+    }
+
+    #[test]
+    fn test_clean_rules_and_raw_rules() {
+        let (rules, _) = ClearUrlsRules::from_json(RULES).unwrap();
+
+        assert_eq!(
+            rules.clean("https://example.com/page?id=1&utm_source=x&FBCLID=abc#top"),
+            Some("https://example.com/page?id=1#top".to_string())
+        );
+        assert_eq!(
+            rules.clean("https://www.amazon.de/dp/B000123/ref=sr_1_1?tag=abc&pd_rd_w=1&th=1"),
+            Some("https://www.amazon.de/dp/B000123?th=1".to_string())
+        );
+        assert_eq!(
+            rules.clean("https://example.com/#utm_source=x&section=2"),
+            Some("https://example.com/#section=2".to_string())
+        );
+        assert!(rules.clean("https://example.com/page?id=1").is_none());
+        assert!(rules.clean("http://localhost:8080/?utm_source=x").is_none());
+    }
+
+    #[test]
+    fn test_redirections() {
+        let (rules, _) = ClearUrlsRules::from_json(RULES).unwrap();
+
+        assert_eq!(
+            rules.clean("https://out.example.com/go?to=https%3A%2F%2Fexample.org%2F%3Futm_medium%3Dx"),
+            Some("https://example.org/".to_string())
+        );
+    }
+
+    #[test]
+    fn test_missing_file_is_empty() {
+        let path = std::env::temp_dir().join(format!("clearurls-missing-{}.json", std::process::id()));
+        let (rules, errors) = ClearUrlsRules::from_file(&path).unwrap();
+
+        assert!(rules.is_empty());
+        assert!(errors.is_empty());
+    }
+}
@@ -1,56 +1,147 @@
-use super::LinkConverterStrategy;
+use super::conversion::extracted_ids;
+use super::options::{frontend_host, supported_options, ConverterOption, ConverterOptions};
+use super::{domain_alternation, LinkConverterStrategy};
 use regex::Regex;
+use std::collections::BTreeMap;
+
+const CANONICAL_DOMAIN: &str = "bsky.app";
+
+/// Converter names and the frontend domains they map to
+const FRONTENDS: &[(&str, &str)] = &[
+    ("bsky", CANONICAL_DOMAIN),
+    ("fxbsky", "fxbsky.app"),
+    ("vxbsky", "vxbsky.app"),
+    ("bskye", "bskye.app"),
+    ("bskyx", "bskyx.app"),
+];
+
+/// Modes each frontend exposes through subdomains and path suffixes
+const OPTIONS: &[(&str, &[ConverterOption])] = &[
+    ("fxbsky", &[ConverterOption::DirectMedia, ConverterOption::Gallery, ConverterOption::Translate]),
+    ("vxbsky", &[ConverterOption::DirectMedia]),
+];
+
+/// A profile given either by handle (`alice.bsky.social`) or by DID (`did:plc:abc123`)
+const ACTOR: &str = r"(did:[a-z]+:[A-Za-z0-9._:%-]+|[A-Za-z0-9][A-Za-z0-9.-]*)";
+
+/// Record key of a post
+const RKEY: &str = r"([A-Za-z0-9._:~-]+)";
 
 pub struct BlueSkyConverter {
     vanilla_regex: Regex,
+    at_uri_regex: Regex,
+    frontend_regex: Regex,
 }
 
 impl BlueSkyConverter {
     pub fn new() -> Self {
+        let frontend_domains = FRONTENDS
+            .iter()
+            .map(|(_, domain)| *domain)
+            .filter(|domain| *domain != CANONICAL_DOMAIN);
+
         Self {
-            // Only matches vanilla bsky.app domain
-            vanilla_regex: Regex::new(
-                r"^(?:https?://)?(?:www\.)?(bsky\.app)/profile/([^/]+)/post/([^/\s]+)"
-            ).unwrap(),
+            // Only matches vanilla bsky.app domain, for posts or a whole profile
+            vanilla_regex: Regex::new(&format!(
+                r"^(?:https?://)?(?:www\.)?bsky\.app/profile/{}(?:/post/{}|/?(?:[?#]|$))",
+                ACTOR, RKEY
+            )).unwrap(),
+            // AT-URIs of posts, or of a whole profile when only the authority is given
+            at_uri_regex: Regex::new(&format!(
+                r"^at://{}(?:/app\.bsky\.feed\.post/{}|/?$)",
+                ACTOR, RKEY
+            )).unwrap(),
+            // Matches links on any known frontend other than bsky.app itself
+            frontend_regex: Regex::new(&format!(
+                r"^(?:https?://)?(?:www\.|[a-z]\.)?(?:{})/profile/{}(?:/post/{}|/?(?:[?#]|$))",
+                domain_alternation(frontend_domains),
+                ACTOR, RKEY
+            )).unwrap(),
         }
     }
+
+    // Path on bsky.app (and the frontends, which mirror it), keeping the handle or DID as given.
+    // Starter pack short links (go.bsky.app) are unwrapped to bsky.app before they get here; the
+    // frontends do not serve starter packs.
+    fn extract_path(&self, url: &str) -> Option<String> {
+        let caps = self.vanilla_regex.captures(url).or_else(|| self.at_uri_regex.captures(url))?;
+        Some(profile_path(caps.get(1)?.as_str(), caps.get(2).map(|m| m.as_str())))
+    }
+}
+
+fn profile_path(actor: &str, post_id: Option<&str>) -> String {
+    match post_id {
+        Some(post_id) => format!("/profile/{}/post/{}", actor, post_id),
+        None => format!("/profile/{}", actor),
+    }
 }
 
 impl LinkConverterStrategy for BlueSkyConverter {
-    fn platform_name(&self) -> &'static str {
+    fn platform_name(&self) -> &str {
         "bluesky"
     }
 
     fn matches(&self, url: &str) -> bool {
-        self.vanilla_regex.is_match(url)
+        self.vanilla_regex.is_match(url) || self.at_uri_regex.is_match(url)
     }
 
     fn convert(&self, url: &str, converter: &str) -> Option<String> {
+        self.convert_with_options(url, converter, &ConverterOptions::default())
+    }
+
+    fn supported_options(&self, converter: &str) -> Vec<ConverterOption> {
+        supported_options(OPTIONS, converter)
+    }
+
+    fn convert_with_options(&self, url: &str, converter: &str, options: &ConverterOptions) -> Option<String> {
         if !self.matches(url) {
             return None;
         }
 
-        let caps = self.vanilla_regex.captures(url)?;
-        let username = caps.get(2)?.as_str();
-        let post_id = caps.get(3)?.as_str();
-
-        let converter_domain = match converter.to_lowercase().as_str() {
-            "bsky" => "bsky.app",
-            "fxbsky" => "fxbsky.app",
-            "vxbsky" => "vxbsky.app",
-            "bskye" => "bskye.app",
-            "bskyx" => "bskyx.app",
-            _ => return None,
+        let mut path = self.extract_path(url)?;
+
+        let converter = converter.to_lowercase();
+        let (_, converter_domain) = FRONTENDS.iter().find(|(name, _)| *name == converter)?;
+
+        // Modes only apply to posts, not to profiles
+        if !path.contains("/post/") {
+            return Some(format!("https://{}{}", converter_domain, path));
+        }
+
+        let supported = self.supported_options(&converter);
+        if let Some(language) = options.language(&supported) {
+            path.push('/');
+            path.push_str(language);
+        }
+
+        Some(format!("https://{}{}", frontend_host(converter_domain, options, &supported), path))
+    }
+
+    fn extract_ids(&self, url: &str) -> BTreeMap<String, String> {
+        let Some(caps) = self.vanilla_regex.captures(url).or_else(|| self.at_uri_regex.captures(url)) else {
+            return BTreeMap::new();
         };
+        extracted_ids([
+            ("actor", caps.get(1).map(|m| m.as_str())),
+            ("post", caps.get(2).map(|m| m.as_str())),
+        ])
+    }
 
-        Some(format!(
-            "https://{}/profile/{}/post/{}",
-            converter_domain, username, post_id
-        ))
+    fn available_converters(&self) -> Vec<String> {
+        FRONTENDS.iter().map(|(name, _)| name.to_string()).collect()
     }
 
-    fn available_converters(&self) -> Vec<&'static str> {
-        vec!["bsky", "fxbsky", "vxbsky", "bskye", "bskyx"]
+    fn is_passthrough(&self, converter: &str) -> bool {
+        FRONTENDS
+            .iter()
+            .any(|(name, domain)| name.eq_ignore_ascii_case(converter) && *domain == CANONICAL_DOMAIN)
+    }
+
+    fn canonicalize(&self, url: &str) -> Option<String> {
+        let caps = self.frontend_regex.captures(url)?;
+        let path = profile_path(caps.get(1)?.as_str(), caps.get(2).map(|m| m.as_str()));
+
+        Some(format!("https://{}{}", CANONICAL_DOMAIN, path))
     }
 }
 
@@ -127,4 +218,69 @@ mod tests {
             assert!(result.ends_with(&format!("/profile/{}/post/{}", expected_user, expected_id)));
         }
     }
+
+    #[test]
+    fn test_bluesky_canonicalize() {
+        let converter = BlueSkyConverter::new();
+
+        for url in [
+            "https://fxbsky.app/profile/user.bsky.social/post/123456",
+            "https://vxbsky.app/profile/user.bsky.social/post/123456",
+            "https://bskye.app/profile/user.bsky.social/post/123456",
+            "http://www.bskyx.app/profile/user.bsky.social/post/123456",
+        ] {
+            assert_eq!(
+                converter.canonicalize(url),
+                Some("https://bsky.app/profile/user.bsky.social/post/123456".to_string())
+            );
+        }
+
+        assert!(converter.canonicalize("https://bsky.app/profile/user.bsky.social/post/123456").is_none());
+    }
+
+    #[test]
+    fn test_at_uris_and_dids() {
+        let converter = BlueSkyConverter::new();
+
+        let cases = vec![
+            (
+                "at://did:plc:abc123xyz/app.bsky.feed.post/3kabc123",
+                "bsky",
+                "https://bsky.app/profile/did:plc:abc123xyz/post/3kabc123",
+            ),
+            (
+                "at://alice.bsky.social/app.bsky.feed.post/3kabc123",
+                "fxbsky",
+                "https://fxbsky.app/profile/alice.bsky.social/post/3kabc123",
+            ),
+            ("at://did:plc:abc123xyz", "vxbsky", "https://vxbsky.app/profile/did:plc:abc123xyz"),
+            (
+                "https://bsky.app/profile/did:plc:abc123xyz/post/3kabc123",
+                "fxbsky",
+                "https://fxbsky.app/profile/did:plc:abc123xyz/post/3kabc123",
+            ),
+            ("https://bsky.app/profile/did:plc:abc123xyz", "fxbsky", "https://fxbsky.app/profile/did:plc:abc123xyz"),
+            ("bsky.app/profile/alice.bsky.social/", "vxbsky", "https://vxbsky.app/profile/alice.bsky.social"),
+        ];
+
+        for (url, converter_type, expected) in cases {
+            assert_eq!(converter.convert(url, converter_type), Some(expected.to_string()), "for {}", url);
+        }
+
+        // Other record types are not posts, and other profile pages are not the profile itself
+        assert!(!converter.matches("at://did:plc:abc123xyz/app.bsky.feed.like/3kabc123"));
+        assert!(!converter.matches("https://bsky.app/profile/did:plc:abc123xyz/lists/3kabc123"));
+        // Starter pack short links are unwrapped to bsky.app, which the frontends do not mirror
+        assert!(!converter.matches("https://go.bsky.app/AbC123"));
+        assert!(!converter.matches("https://bsky.app/starter-pack-short/AbC123"));
+
+        assert_eq!(
+            converter.canonicalize("https://fxbsky.app/profile/did:plc:abc123xyz/post/3kabc123"),
+            Some("https://bsky.app/profile/did:plc:abc123xyz/post/3kabc123".to_string())
+        );
+        assert_eq!(
+            converter.canonicalize("https://fxbsky.app/profile/did:plc:abc123xyz"),
+            Some("https://bsky.app/profile/did:plc:abc123xyz".to_string())
+        );
+    }
 }
@@ -0,0 +1,107 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// A successful conversion, with what was recognised in the original link
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct Conversion {
+    /// Platform that recognised the link, empty for a cleaned link no platform recognises
+    pub platform: String,
+    /// Converter that produced the link, empty for a cleaned link
+    pub converter: String,
+    /// Identifiers taken from the original link, e.g. `user` and `status` for a tweet
+    pub ids: BTreeMap<String, String>,
+    pub original: String,
+    pub url: String,
+    /// No converter ran; only redirect wrappers or tracking parameters were removed
+    #[serde(default)]
+    pub cleaned_only: bool,
+}
+
+/// Why a link was left alone
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum ConversionError {
+    /// No platform recognises the link
+    NotSupported,
+    PlatformDisabled { platform: String },
+    NoConverterSelected { platform: String },
+    /// Every converter in the platform's preference list is marked unavailable
+    NoConverterAvailable { platform: String },
+    UnknownConverter { platform: String, converter: String },
+    /// The link is already on a frontend, or already in the form the converter produces
+    AlreadyConverted { platform: String, converter: String },
+    /// The converter would produce the link it was given
+    IdentityConversion { platform: String, converter: String },
+}
+
+impl fmt::Display for ConversionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConversionError::NotSupported => write!(f, "The link is not from a supported platform"),
+            ConversionError::PlatformDisabled { platform } => write!(f, "Link conversion is disabled for {}", platform),
+            ConversionError::NoConverterSelected { platform } => write!(f, "No converter is selected for {}", platform),
+            ConversionError::NoConverterAvailable { platform } => {
+                write!(f, "Every converter for {} is marked unavailable", platform)
+            }
+            ConversionError::UnknownConverter { platform, converter } => {
+                write!(f, "Converter '{}' is not available for {}", converter, platform)
+            }
+            ConversionError::AlreadyConverted { platform, converter } => {
+                write!(f, "The {} link is already converted for '{}'", platform, converter)
+            }
+            ConversionError::IdentityConversion { platform, converter } => {
+                write!(f, "Converter '{}' leaves {} links unchanged", converter, platform)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ConversionError {}
+
+impl ConversionError {
+    /// Errors other than NotSupported mean a platform recognised the link
+    pub fn is_recognized(&self) -> bool {
+        !matches!(self, ConversionError::NotSupported)
+    }
+}
+
+/// Checks if two links point at the same place, ignoring scheme, www. prefix, query and trailing slash
+pub fn same_link(a: &str, b: &str) -> bool {
+    fn normalized(url: &str) -> String {
+        let without_scheme = url.split_once("://").map_or(url, |(_, rest)| rest);
+        let without_www = without_scheme.strip_prefix("www.").unwrap_or(without_scheme);
+        let path = without_www.split(['?', '#']).next().unwrap_or(without_www);
+        path.trim_end_matches('/').to_lowercase()
+    }
+
+    normalized(a) == normalized(b)
+}
+
+/// Builds the identifiers of a conversion, skipping the ones a link does not have
+pub(crate) fn extracted_ids<'a>(ids: impl IntoIterator<Item = (&'a str, Option<&'a str>)>) -> BTreeMap<String, String> {
+    ids.into_iter()
+        .filter_map(|(name, value)| Some((name.to_string(), value?.to_string())))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_same_link() {
+        assert!(same_link("https://bsky.app/profile/a/post/1?ref=x", "http://www.bsky.app/profile/a/post/1/"));
+        assert!(!same_link("https://bsky.app/profile/a/post/1", "https://fxbsky.app/profile/a/post/1"));
+    }
+
+    #[test]
+    fn test_error_serialization() {
+        let error = ConversionError::PlatformDisabled { platform: "twitter".to_string() };
+        let json = serde_json::to_value(&error).unwrap();
+
+        assert_eq!(json["kind"], "platform_disabled");
+        assert_eq!(json["platform"], "twitter");
+        assert_eq!(error.to_string(), "Link conversion is disabled for twitter");
+    }
+}
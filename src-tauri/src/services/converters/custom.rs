@@ -0,0 +1,180 @@
+use super::declarative::placeholders;
+use super::{normalize_host, LinkConverterStrategy};
+use serde::{Deserialize, Serialize};
+use std::sync::{Arc, RwLock};
+
+/// A self-hosted frontend the user added to a platform, e.g. an internal FxEmbed instance
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct CustomFrontend {
+    /// Converter name shown next to the built-in ones
+    pub name: String,
+    pub domain: String,
+    /// Path on the frontend, with ids of the original link between braces, e.g. "/{user}/status/{status}"
+    pub template: String,
+}
+
+struct CompiledFrontend {
+    name: String,
+    host: String,
+    template: String,
+}
+
+/// Converter for the self-hosted frontends of one platform. Links are recognised by the platform's
+/// built-in converter, whose ids fill in each frontend's path template.
+pub struct CustomFrontendConverter {
+    base: Arc<dyn LinkConverterStrategy>,
+    frontends: RwLock<Vec<CompiledFrontend>>,
+}
+
+impl CustomFrontendConverter {
+    pub fn new(base: Arc<dyn LinkConverterStrategy>) -> Self {
+        Self {
+            base,
+            frontends: RwLock::new(Vec::new()),
+        }
+    }
+
+    /// Replace the frontends, skipping (and reporting) entries without a name or domain, entries
+    /// named like one of the built-in converters or a `reserved` name, and names with a ':', which
+    /// separates tray menu ids
+    pub fn configure(&self, frontends: &[CustomFrontend], reserved: &[String]) -> Vec<String> {
+        let mut compiled: Vec<CompiledFrontend> = Vec::new();
+        let mut errors = Vec::new();
+        let platform = self.base.platform_name();
+
+        for frontend in frontends {
+            let name = frontend.name.trim().to_lowercase();
+            let Some(host) = normalize_host(&frontend.domain) else {
+                errors.push(format!("Custom converter '{}' for {} has no domain", frontend.name, platform));
+                continue;
+            };
+            if name.is_empty() {
+                errors.push(format!("Custom converter on {} for {} has no name", host, platform));
+                continue;
+            }
+            if name.contains(':') {
+                errors.push(format!("Custom converter '{}' for {} cannot contain ':'", name, platform));
+                continue;
+            }
+            if self.base.available_converters().contains(&name) || reserved.contains(&name) || compiled.iter().any(|c| c.name == name) {
+                errors.push(format!("Custom converter '{}' for {} is already defined", name, platform));
+                continue;
+            }
+
+            let template = frontend.template.trim();
+            compiled.push(CompiledFrontend {
+                name,
+                host,
+                template: if template.starts_with('/') {
+                    template.to_string()
+                } else {
+                    format!("/{}", template)
+                },
+            });
+        }
+
+        *self.frontends.write().unwrap() = compiled;
+        errors
+    }
+}
+
+// Host of a link without scheme or www. prefix, e.g. "fx.example.internal"
+fn link_host(url: &str) -> String {
+    let without_scheme = url.split_once("://").map_or(url, |(_, rest)| rest);
+    let without_www = without_scheme.strip_prefix("www.").unwrap_or(without_scheme);
+    without_www.split(['/', '?', '#']).next().unwrap_or(without_www).to_lowercase()
+}
+
+impl LinkConverterStrategy for CustomFrontendConverter {
+    fn platform_name(&self) -> &str {
+        self.base.platform_name()
+    }
+
+    fn matches(&self, url: &str) -> bool {
+        !self.frontends.read().unwrap().is_empty() && self.base.matches(url)
+    }
+
+    fn convert(&self, url: &str, converter: &str) -> Option<String> {
+        let frontends = self.frontends.read().unwrap();
+        let frontend = frontends.iter().find(|f| f.name.eq_ignore_ascii_case(converter))?;
+        if !self.base.matches(url) {
+            return None;
+        }
+
+        // Every placeholder needs an id, so links of another kind are left alone
+        let ids = self.base.extract_ids(url);
+        let mut path = frontend.template.clone();
+        for placeholder in placeholders(&frontend.template) {
+            path = path.replace(&format!("{{{}}}", placeholder), ids.get(placeholder)?);
+        }
+
+        Some(format!("https://{}{}", frontend.host, path))
+    }
+
+    fn available_converters(&self) -> Vec<String> {
+        self.frontends.read().unwrap().iter().map(|f| f.name.clone()).collect()
+    }
+
+    fn is_converted(&self, url: &str, converter: &str) -> bool {
+        let host = link_host(url);
+        self.frontends
+            .read()
+            .unwrap()
+            .iter()
+            .any(|f| f.name.eq_ignore_ascii_case(converter) && f.host.strip_prefix("www.").unwrap_or(&f.host) == host)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::converters::twitter::TwitterConverter;
+
+    fn frontend(name: &str, domain: &str, template: &str) -> CustomFrontend {
+        CustomFrontend {
+            name: name.to_string(),
+            domain: domain.to_string(),
+            template: template.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_custom_frontend_convert() {
+        let converter = CustomFrontendConverter::new(Arc::new(TwitterConverter::new()));
+        let errors = converter.configure(&[
+            frontend("Internal", "https://fx.example.internal/", "/{user}/status/{status}"),
+            frontend("byid", "embed.example.internal", "i/{status}"),
+        ], &[]);
+        assert!(errors.is_empty());
+
+        assert_eq!(converter.available_converters(), vec!["internal", "byid"]);
+        assert_eq!(
+            converter.convert("https://x.com/user/status/123?s=20", "internal"),
+            Some("https://fx.example.internal/user/status/123".to_string())
+        );
+        assert_eq!(
+            converter.convert("https://x.com/user/status/123", "byid"),
+            Some("https://embed.example.internal/i/123".to_string())
+        );
+        assert!(converter.convert("https://example.com/user/status/123", "internal").is_none());
+        assert!(converter.is_converted("https://fx.example.internal/user/status/123", "internal"));
+    }
+
+    #[test]
+    fn test_custom_frontend_errors() {
+        let converter = CustomFrontendConverter::new(Arc::new(TwitterConverter::new()));
+        let errors = converter.configure(&[
+            frontend("fxtwitter", "fx.example.internal", "/{user}/status/{status}"),
+            frontend("", "fx.example.internal", "/{status}"),
+            frontend("nodomain", " ", "/{status}"),
+            frontend("fx:internal", "fx.example.internal", "/{status}"),
+            frontend("internal", "fx.example.internal", "/{user}/status/{missing}"),
+            frontend("nitter", "nitter.example.internal", "/{user}/status/{status}"),
+        ], &["nitter".to_string()]);
+
+        assert_eq!(errors.len(), 5);
+        assert_eq!(converter.available_converters(), vec!["internal"]);
+        // Templates with ids the platform does not provide leave links alone
+        assert!(converter.convert("https://x.com/user/status/123", "internal").is_none());
+    }
+}
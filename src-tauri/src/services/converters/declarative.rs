@@ -0,0 +1,285 @@
+use super::LinkConverterStrategy;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+
+const DEFINITIONS_TEMPLATE: &str = r#"# User-defined link converters, loaded at startup.
+#
+# Each entry converts links matching `pattern` for `platform` into `template`.
+# Placeholders in the template refer to named capture groups in the pattern,
+# or to positional groups listed under `captures`. The platform must be one the
+# app supports. A definition whose platform and name match a built-in converter
+# replaces it.
+#
+# converters:
+#   - platform: twitter
+#     name: fixupx
+#     pattern: '^(?:https?://)?(?:www\.)?(?:twitter|x)\.com/([^/]+)/status/([0-9]+)'
+#     captures: [user, id]
+#     template: 'https://fixupx.com/{user}/status/{id}'
+converters: []
+"#;
+
+/// A single converter as written in the user's definitions file
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ConverterDefinition {
+    pub platform: String,
+    pub name: String,
+    pub pattern: String,
+    #[serde(default)]
+    pub captures: Vec<String>,
+    pub template: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Default)]
+pub struct ConverterDefinitions {
+    #[serde(default)]
+    pub converters: Vec<ConverterDefinition>,
+}
+
+impl ConverterDefinitions {
+    /// Load definitions from a YAML file, writing a commented template if it does not exist
+    pub fn from_file_or_default(path: &Path) -> Result<Self, String> {
+        if !path.exists() {
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent)
+                    .map_err(|e| format!("Failed to create converters directory: {}", e))?;
+            }
+            fs::write(path, DEFINITIONS_TEMPLATE)
+                .map_err(|e| format!("Failed to write converters file: {}", e))?;
+            return Ok(Self::default());
+        }
+
+        let yaml = fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read converters file: {}", e))?;
+        Self::from_yaml(&yaml)
+    }
+
+    pub fn from_yaml(yaml: &str) -> Result<Self, String> {
+        serde_yaml::from_str(yaml).map_err(|e| format!("Invalid converters file: {}", e))
+    }
+}
+
+struct DeclarativeRule {
+    name: String,
+    regex: Regex,
+    captures: Vec<String>,
+    template: String,
+}
+
+impl DeclarativeRule {
+    fn compile(definition: &ConverterDefinition) -> Result<Self, String> {
+        // Tray menu ids end with ":<converter>"
+        if definition.name.contains(':') {
+            return Err(format!("Converter '{}': names cannot contain ':'", definition.name));
+        }
+
+        let regex = Regex::new(&definition.pattern)
+            .map_err(|e| format!("Converter '{}': invalid pattern: {}", definition.name, e))?;
+
+        let rule = Self {
+            name: definition.name.to_lowercase(),
+            regex,
+            captures: definition.captures.clone(),
+            template: definition.template.clone(),
+        };
+
+        for placeholder in placeholders(&rule.template) {
+            if !rule.has_capture(placeholder) {
+                return Err(format!(
+                    "Converter '{}': template uses unknown capture '{}'",
+                    definition.name, placeholder
+                ));
+            }
+        }
+
+        Ok(rule)
+    }
+
+    fn has_capture(&self, name: &str) -> bool {
+        self.captures.iter().any(|c| c == name) || self.regex.capture_names().any(|c| c == Some(name))
+    }
+
+    fn render(&self, url: &str) -> Option<String> {
+        let caps = self.regex.captures(url)?;
+        let mut output = self.template.clone();
+
+        for placeholder in placeholders(&self.template) {
+            let value = match self.captures.iter().position(|c| c == placeholder) {
+                Some(index) => caps.get(index + 1),
+                None => caps.name(placeholder),
+            };
+            output = output.replace(&format!("{{{}}}", placeholder), value.map_or("", |m| m.as_str()));
+        }
+
+        Some(output)
+    }
+}
+
+// Names between braces in an output template, e.g. "user" and "id" in "/{user}/status/{id}"
+pub(crate) fn placeholders(template: &str) -> Vec<&str> {
+    template
+        .split('{')
+        .skip(1)
+        .filter_map(|part| part.split_once('}').map(|(name, _)| name))
+        .collect()
+}
+
+/// Converter strategy built from the user definitions of a single converter, so it only
+/// matches links its own rules convert
+pub struct DeclarativeConverter {
+    platform: String,
+    name: String,
+    rules: Vec<DeclarativeRule>,
+}
+
+impl DeclarativeConverter {
+    /// Group definitions by platform and converter, skipping (and reporting) the ones that fail to compile
+    pub fn from_definitions(definitions: &[ConverterDefinition]) -> (Vec<Self>, Vec<String>) {
+        let mut converters: Vec<Self> = Vec::new();
+        let mut errors = Vec::new();
+
+        for definition in definitions {
+            let rule = match DeclarativeRule::compile(definition) {
+                Ok(rule) => rule,
+                Err(e) => {
+                    errors.push(e);
+                    continue;
+                }
+            };
+
+            let platform = definition.platform.to_lowercase();
+            match converters.iter_mut().find(|c| c.platform == platform && c.name == rule.name) {
+                Some(converter) => converter.rules.push(rule),
+                None => converters.push(Self {
+                    platform,
+                    name: rule.name.clone(),
+                    rules: vec![rule],
+                }),
+            }
+        }
+
+        (converters, errors)
+    }
+}
+
+impl LinkConverterStrategy for DeclarativeConverter {
+    fn platform_name(&self) -> &str {
+        &self.platform
+    }
+
+    fn matches(&self, url: &str) -> bool {
+        self.rules.iter().any(|rule| rule.regex.is_match(url))
+    }
+
+    fn convert(&self, url: &str, converter: &str) -> Option<String> {
+        if !self.name.eq_ignore_ascii_case(converter) {
+            return None;
+        }
+        self.rules.iter().find_map(|rule| rule.render(url))
+    }
+
+    fn available_converters(&self) -> Vec<String> {
+        vec![self.name.clone()]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const YAML: &str = r#"
+converters:
+  - platform: twitter
+    name: fixupx
+    pattern: '^(?:https?://)?(?:www\.)?(?:twitter|x)\.com/([^/]+)/status/([0-9]+)'
+    captures: [user, id]
+    template: 'https://fixupx.com/{user}/status/{id}'
+  - platform: Twitter
+    name: MyEmbed
+    pattern: '^(?:https?://)?(?:www\.)?(?:twitter|x)\.com/(?P<user>[^/]+)/status/(?P<id>[0-9]+)'
+    template: 'https://embed.example.com/{user}/{id}'
+  - platform: tiktok
+    name: broken
+    pattern: '^(unclosed'
+    template: 'https://example.com/'
+"#;
+
+    #[test]
+    fn test_parse_definitions() {
+        let definitions = ConverterDefinitions::from_yaml(YAML).unwrap();
+
+        assert_eq!(definitions.converters.len(), 3);
+        assert_eq!(definitions.converters[0].captures, vec!["user", "id"]);
+        assert!(definitions.converters[1].captures.is_empty());
+    }
+
+    #[test]
+    fn test_invalid_definitions_are_reported() {
+        let definitions = ConverterDefinitions::from_yaml(YAML).unwrap();
+        let (converters, errors) = DeclarativeConverter::from_definitions(&definitions.converters);
+
+        assert_eq!(converters.len(), 2);
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].contains("broken"));
+
+        let unknown = ConverterDefinition {
+            platform: "twitter".to_string(),
+            name: "typo".to_string(),
+            pattern: r"^https://x\.com/(\d+)".to_string(),
+            captures: vec!["id".to_string()],
+            template: "https://example.com/{status}".to_string(),
+        };
+        let (_, errors) = DeclarativeConverter::from_definitions(&[unknown]);
+        assert!(errors[0].contains("unknown capture 'status'"));
+
+        let separator = ConverterDefinition {
+            name: "my:embed".to_string(),
+            ..definitions.converters[0].clone()
+        };
+        let (converters, errors) = DeclarativeConverter::from_definitions(&[separator]);
+        assert!(converters.is_empty());
+        assert!(errors[0].contains("cannot contain ':'"));
+    }
+
+    #[test]
+    fn test_declarative_convert() {
+        let definitions = ConverterDefinitions::from_yaml(YAML).unwrap();
+        let (converters, _) = DeclarativeConverter::from_definitions(&definitions.converters);
+        let (fixupx, myembed) = (&converters[0], &converters[1]);
+
+        assert_eq!(fixupx.platform_name(), "twitter");
+        assert_eq!(fixupx.available_converters(), vec!["fixupx"]);
+        assert_eq!(myembed.available_converters(), vec!["myembed"]);
+        assert!(fixupx.matches("https://x.com/user/status/123"));
+        assert!(!fixupx.matches("https://fxtwitter.com/user/status/123"));
+
+        assert_eq!(
+            fixupx.convert("https://x.com/user/status/123?s=20", "fixupx"),
+            Some("https://fixupx.com/user/status/123".to_string())
+        );
+        assert_eq!(
+            myembed.convert("https://twitter.com/user/status/123", "MyEmbed"),
+            Some("https://embed.example.com/user/123".to_string())
+        );
+        assert!(fixupx.convert("https://x.com/user/status/123", "myembed").is_none());
+        assert!(fixupx.convert("https://x.com/user/status/123", "vxtwitter").is_none());
+    }
+
+    #[test]
+    fn test_missing_file_creates_template() {
+        let path = std::env::temp_dir().join(format!("converters-test-{}.yaml", std::process::id()));
+        let _ = fs::remove_file(&path);
+
+        let definitions = ConverterDefinitions::from_file_or_default(&path).unwrap();
+        assert!(definitions.converters.is_empty());
+        assert!(path.exists());
+
+        // The written template must load back cleanly
+        let reloaded = ConverterDefinitions::from_file_or_default(&path).unwrap();
+        assert!(reloaded.converters.is_empty());
+
+        let _ = fs::remove_file(&path);
+    }
+}
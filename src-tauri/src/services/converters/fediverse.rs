@@ -0,0 +1,222 @@
+use super::conversion::extracted_ids;
+use super::{domain_alternation, normalize_host, LinkConverterStrategy};
+use crate::services::query_params::{decode_component, encode_component};
+use regex::Regex;
+use std::collections::BTreeMap;
+use std::sync::RwLock;
+
+/// Status path on a Mastodon-compatible instance: `/@<user>/<id>` or `/users/<user>/statuses/<id>`.
+/// Users from other instances appear as `/@<user>@<domain>/<id>`.
+const STATUS_PATH: &str = r"(?:/@([A-Za-z0-9_.-]+(?:@[A-Za-z0-9.-]+)?)/([0-9]+)|/users/([A-Za-z0-9_.-]+)/statuses/([0-9]+))(?:[/?#]|$)";
+
+/// Instance-dependent state, rebuilt whenever the user edits their instance list
+#[derive(Default)]
+struct FediverseInstances {
+    status_regex: Option<Regex>,
+    home_instance: Option<String>,
+    embed_frontend: Option<String>,
+    embed_regex: Option<Regex>,
+    interaction_regex: Option<Regex>,
+}
+
+/// Converter for Mastodon-compatible servers. Posts can live on any domain, so the
+/// recognised hosts come from the user's configuration instead of a fixed regex.
+pub struct FediverseConverter {
+    instances: RwLock<FediverseInstances>,
+}
+
+impl FediverseConverter {
+    pub fn new() -> Self {
+        Self {
+            instances: RwLock::new(FediverseInstances::default()),
+        }
+    }
+
+    /// Replace the recognised instances, the user's home instance and the embed frontend.
+    /// Blank entries are ignored. Domains are matched literally; if the patterns still cannot be
+    /// built (e.g. too many instances), the previous configuration is kept and the error returned.
+    pub fn configure(&self, instances: &[String], home_instance: &str, embed_frontend: &str) -> Result<(), String> {
+        let hosts: Vec<String> = instances.iter().filter_map(|i| normalize_host(i)).collect();
+        let home_instance = normalize_host(home_instance);
+        let embed_frontend = normalize_host(embed_frontend);
+        let compile = |pattern: String| Regex::new(&pattern).map_err(|e| format!("Invalid fediverse settings: {}", e));
+
+        let status_regex = (!hosts.is_empty())
+            .then(|| {
+                compile(format!(
+                    r"^(?:https?://)?({}){}",
+                    domain_alternation(hosts.iter().map(String::as_str)),
+                    STATUS_PATH
+                ))
+            })
+            .transpose()?;
+        // Embed frontends carry the original instance as the first path segment
+        let embed_regex = embed_frontend
+            .as_ref()
+            .map(|frontend| {
+                compile(format!(
+                    r"^(?:https?://)?{}/([A-Za-z0-9.-]+\.[A-Za-z]+){}",
+                    regex::escape(frontend),
+                    STATUS_PATH
+                ))
+            })
+            .transpose()?;
+        let interaction_regex = home_instance
+            .as_ref()
+            .map(|home| {
+                compile(format!(
+                    r"^(?:https?://)?{}/authorize_interaction\?(?:[^#\s]*&)?uri=([^&#\s]+)",
+                    regex::escape(home)
+                ))
+            })
+            .transpose()?;
+
+        *self.instances.write().unwrap() = FediverseInstances {
+            status_regex,
+            home_instance,
+            embed_frontend,
+            embed_regex,
+            interaction_regex,
+        };
+        Ok(())
+    }
+}
+
+// Instance, user and status id of a status link
+fn status_parts<'a>(caps: &regex::Captures<'a>, first_group: usize) -> Option<(&'a str, &'a str)> {
+    let user = caps.get(first_group).or_else(|| caps.get(first_group + 2))?.as_str();
+    let status_id = caps.get(first_group + 1).or_else(|| caps.get(first_group + 3))?.as_str();
+    Some((user, status_id))
+}
+
+impl LinkConverterStrategy for FediverseConverter {
+    fn platform_name(&self) -> &str {
+        "fediverse"
+    }
+
+    fn matches(&self, url: &str) -> bool {
+        let instances = self.instances.read().unwrap();
+        instances.status_regex.as_ref().is_some_and(|regex| regex.is_match(url))
+    }
+
+    fn convert(&self, url: &str, converter: &str) -> Option<String> {
+        let instances = self.instances.read().unwrap();
+        let caps = instances.status_regex.as_ref()?.captures(url)?;
+        let instance = caps.get(1)?.as_str().to_lowercase();
+        let (user, status_id) = status_parts(&caps, 2)?;
+
+        match converter.to_lowercase().as_str() {
+            "embed" => {
+                let frontend = instances.embed_frontend.as_ref()?;
+                Some(format!("https://{}/{}/@{}/{}", frontend, instance, user, status_id))
+            }
+            "home" => {
+                // Links on the home instance already open there
+                let home = instances.home_instance.as_ref().filter(|home| **home != instance)?;
+                let status_url = format!("https://{}/@{}/{}", instance, user, status_id);
+                Some(format!("https://{}/authorize_interaction?uri={}", home, encode_component(&status_url)))
+            }
+            _ => None,
+        }
+    }
+
+    fn extract_ids(&self, url: &str) -> BTreeMap<String, String> {
+        let instances = self.instances.read().unwrap();
+        let Some(caps) = instances.status_regex.as_ref().and_then(|regex| regex.captures(url)) else {
+            return BTreeMap::new();
+        };
+        let (user, status_id) = status_parts(&caps, 2).unzip();
+        extracted_ids([
+            ("instance", caps.get(1).map(|m| m.as_str())),
+            ("user", user),
+            ("status", status_id),
+        ])
+    }
+
+    fn available_converters(&self) -> Vec<String> {
+        vec!["embed".to_string(), "home".to_string()]
+    }
+
+    fn canonicalize(&self, url: &str) -> Option<String> {
+        let instances = self.instances.read().unwrap();
+
+        if let Some(caps) = instances.embed_regex.as_ref().and_then(|regex| regex.captures(url)) {
+            let instance = caps.get(1)?.as_str();
+            let (user, status_id) = status_parts(&caps, 2)?;
+            return Some(format!("https://{}/@{}/{}", instance, user, status_id));
+        }
+
+        let caps = instances.interaction_regex.as_ref()?.captures(url)?;
+        decode_component(caps.get(1)?.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn configured() -> FediverseConverter {
+        let converter = FediverseConverter::new();
+        converter.configure(
+            &["mastodon.social".to_string(), "https://Fosstodon.org/".to_string(), " ".to_string()],
+            "hachyderm.io",
+            "embed.example.com",
+        ).unwrap();
+        converter
+    }
+
+    #[test]
+    fn test_fediverse_matches_configured_instances() {
+        let converter = FediverseConverter::new();
+        assert!(!converter.matches("https://mastodon.social/@user/111222333"));
+
+        let converter = configured();
+        assert!(converter.matches("https://mastodon.social/@user/111222333"));
+        assert!(converter.matches("https://fosstodon.org/@user@other.example/111222333"));
+        assert!(converter.matches("https://mastodon.social/users/user/statuses/111222333"));
+
+        assert!(!converter.matches("https://mastodon.online/@user/111222333"));
+        assert!(!converter.matches("https://mastodon.social/@user"));
+        assert!(!converter.matches("https://mastodon.social.evil.com/@user/111222333"));
+
+        // Domains are matched literally, even with regex syntax in them
+        converter.configure(&["mastodon.(social".to_string(), "*.example".to_string()], "home.[x", "e+.example").unwrap();
+        assert!(converter.matches("https://mastodon.(social/@user/111222333"));
+        assert!(!converter.matches("https://mastodon.social/@user/111222333"));
+    }
+
+    #[test]
+    fn test_fediverse_convert() {
+        let converter = configured();
+
+        assert_eq!(
+            converter.convert("https://mastodon.social/@user/111222333", "embed"),
+            Some("https://embed.example.com/mastodon.social/@user/111222333".to_string())
+        );
+        assert_eq!(
+            converter.convert("https://mastodon.social/users/user/statuses/111222333", "home"),
+            Some("https://hachyderm.io/authorize_interaction?uri=https%3A%2F%2Fmastodon.social%2F%40user%2F111222333".to_string())
+        );
+        assert!(converter.convert("https://mastodon.social/@user/111222333", "fxtwitter").is_none());
+
+        // Nothing to do for posts already on the home instance
+        converter.configure(&["hachyderm.io".to_string()], "hachyderm.io", "").unwrap();
+        assert!(converter.convert("https://hachyderm.io/@user/111222333", "home").is_none());
+        assert!(converter.convert("https://hachyderm.io/@user/111222333", "embed").is_none());
+    }
+
+    #[test]
+    fn test_fediverse_canonicalize() {
+        let converter = configured();
+
+        assert_eq!(
+            converter.canonicalize("https://embed.example.com/mastodon.social/@user/111222333"),
+            Some("https://mastodon.social/@user/111222333".to_string())
+        );
+        assert_eq!(
+            converter.canonicalize("https://hachyderm.io/authorize_interaction?uri=https%3A%2F%2Fmastodon.social%2F%40user%2F111222333"),
+            Some("https://mastodon.social/@user/111222333".to_string())
+        );
+        assert!(converter.canonicalize("https://mastodon.social/@user/111222333").is_none());
+    }
+}
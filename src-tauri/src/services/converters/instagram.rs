@@ -1,23 +1,70 @@
-use super::LinkConverterStrategy;
+use super::conversion::extracted_ids;
+use super::{domain_alternation, LinkConverterStrategy};
+use crate::services::query_params::param_value;
 use regex::Regex;
+use std::collections::BTreeMap;
+
+/// Converter names and the frontend domains they map to
+const FRONTENDS: &[(&str, &str)] = &[
+    ("ddinstagram", "ddinstagram.com"),
+    ("kkinstagram", "kkinstagram.com"),
+    ("instagramez", "instagramez.com"),
+    ("eeinstagram", "eeinstagram.com"),
+];
+
+/// Post path shared by vanilla and frontend links: an optional username followed by
+/// `/p/<id>`, `/reel/<id>`, `/reels/<id>` or `/tv/<id>`
+const POST_PATH: &str = r"/(?:([^/?#\s]+)/)?(p|reels?|tv)/([A-Za-z0-9_-]+)(?:[/?#]|$)";
 
 pub struct InstagramConverter {
     vanilla_regex: Regex,
+    frontend_regex: Regex,
 }
 
 impl InstagramConverter {
     pub fn new() -> Self {
         Self {
-      // Only matches vanilla instagram.com domain
-      vanilla_regex: Regex::new(
-        r"^https?:\/\/(?:www\.)?instagram\.com\/([^\/]+)\/reel\/([A-Za-z0-9_-]+)(?:\/|\?|$)"
-      ).unwrap(),
+            // Only matches vanilla instagram.com domain
+            vanilla_regex: Regex::new(&format!(
+                r"^https?://(?:www\.|m\.)?instagram\.com{}",
+                POST_PATH
+            )).unwrap(),
+            // Matches links on any known frontend
+            frontend_regex: Regex::new(&format!(
+                r"^https?://(?:www\.|[a-z]\.)?(?:{}){}",
+                domain_alternation(FRONTENDS.iter().map(|(_, domain)| *domain)),
+                POST_PATH
+            )).unwrap(),
+        }
     }
+
+    // Rebuilds the post path, normalising /reels/ to /reel/ and keeping the carousel index
+    fn post_path(url: &str, caps: &regex::Captures) -> Option<String> {
+        let kind = match caps.get(2)?.as_str() {
+            "reels" => "reel",
+            kind => kind,
+        };
+        let post_id = caps.get(3)?.as_str();
+
+        let mut path = match caps.get(1) {
+            Some(username) => format!("/{}/{}/{}", username.as_str(), kind, post_id),
+            None => format!("/{}/{}", kind, post_id),
+        };
+        if let Some(index) = carousel_index(url) {
+            path.push_str(&format!("?img_index={}", index));
+        }
+
+        Some(path)
     }
 }
 
+// The `img_index` query parameter selects an image inside a carousel post
+fn carousel_index(url: &str) -> Option<&str> {
+    param_value(url, "img_index").filter(|index| !index.is_empty() && index.chars().all(|c| c.is_ascii_digit()))
+}
+
 impl LinkConverterStrategy for InstagramConverter {
-    fn platform_name(&self) -> &'static str {
+    fn platform_name(&self) -> &str {
         "instagram"
     }
 
@@ -31,25 +78,34 @@ impl LinkConverterStrategy for InstagramConverter {
         }
 
         let caps = self.vanilla_regex.captures(url)?;
-        let username = caps.get(1)?.as_str();
-        let post_id = caps.get(2)?.as_str();
-
-        let converter_domain = match converter.to_lowercase().as_str() {
-            "ddinstagram" => "ddinstagram.com",
-            "kkinstagram" => "kkinstagram.com",
-            "instagramez" => "instagramez.com",
-            "eeinstagram" => "eeinstagram.com",
-            _ => return None,
+        let path = Self::post_path(url, &caps)?;
+
+        let converter = converter.to_lowercase();
+        let (_, converter_domain) = FRONTENDS.iter().find(|(name, _)| *name == converter)?;
+
+        Some(format!("https://{}{}", converter_domain, path))
+    }
+
+    fn extract_ids(&self, url: &str) -> BTreeMap<String, String> {
+        let Some(caps) = self.vanilla_regex.captures(url) else {
+            return BTreeMap::new();
         };
+        extracted_ids([
+            ("user", caps.get(1).map(|m| m.as_str())),
+            ("post", caps.get(3).map(|m| m.as_str())),
+            ("image", carousel_index(url)),
+        ])
+    }
 
-        Some(format!(
-            "https://{}/{}/reel/{}",
-            converter_domain, username, post_id
-        ))
+    fn available_converters(&self) -> Vec<String> {
+        FRONTENDS.iter().map(|(name, _)| name.to_string()).collect()
     }
 
-    fn available_converters(&self) -> Vec<&'static str> {
-        vec!["ddinstagram", "kkinstagram", "instagramez", "eeinstagram"]
+    fn canonicalize(&self, url: &str) -> Option<String> {
+        let caps = self.frontend_regex.captures(url)?;
+        let path = Self::post_path(url, &caps)?;
+
+        Some(format!("https://www.instagram.com{}", path))
     }
 }
 
@@ -62,7 +118,12 @@ mod tests {
         let converter = InstagramConverter::new();
 
         assert!(converter.matches("https://www.instagram.com/username/reel/ABC123/"));
-        assert!(!converter.matches("https://www.instagram.com/method_gg/p/DSaTYOqCIOg/"));
+        assert!(converter.matches("https://www.instagram.com/method_gg/p/DSaTYOqCIOg/"));
+        assert!(converter.matches("https://www.instagram.com/reel/ABC123/?igsh=xyz"));
+        assert!(converter.matches("https://www.instagram.com/reels/ABC123/"));
+        assert!(converter.matches("https://www.instagram.com/tv/ABC123"));
+        assert!(!converter.matches("https://www.instagram.com/username/"));
+        assert!(!converter.matches("https://www.instagram.com/stories/username/123/"));
 
         // Test conversion not matching already converted links
         assert!(!converter.matches("https://ddinstagram.com/username/reel/ABC123/"));
@@ -93,4 +154,42 @@ mod tests {
             )
             .is_none());
     }
+
+    #[test]
+    fn test_instagram_canonicalize() {
+        let converter = InstagramConverter::new();
+
+        assert_eq!(
+            converter.canonicalize("https://ddinstagram.com/username/reel/ABC123/"),
+            Some("https://www.instagram.com/username/reel/ABC123".to_string())
+        );
+        assert_eq!(
+            converter.canonicalize("https://www.kkinstagram.com/username/reel/ABC123?igsh=xyz"),
+            Some("https://www.instagram.com/username/reel/ABC123".to_string())
+        );
+        assert!(converter.canonicalize("https://www.instagram.com/username/reel/ABC123/").is_none());
+    }
+
+    #[test]
+    fn test_post_kinds() {
+        let converter = InstagramConverter::new();
+
+        let cases = vec![
+            ("https://www.instagram.com/p/ABC123/", "https://kkinstagram.com/p/ABC123"),
+            ("https://www.instagram.com/user/p/ABC123/", "https://kkinstagram.com/user/p/ABC123"),
+            ("https://www.instagram.com/reel/ABC123/?igsh=xyz", "https://kkinstagram.com/reel/ABC123"),
+            ("https://www.instagram.com/reels/ABC123/", "https://kkinstagram.com/reel/ABC123"),
+            ("https://instagram.com/tv/ABC123", "https://kkinstagram.com/tv/ABC123"),
+            ("https://www.instagram.com/p/ABC123/?img_index=3&igsh=xyz", "https://kkinstagram.com/p/ABC123?img_index=3"),
+        ];
+
+        for (url, expected) in cases {
+            assert_eq!(converter.convert(url, "kkinstagram"), Some(expected.to_string()), "for {}", url);
+        }
+
+        assert_eq!(
+            converter.canonicalize("https://ddinstagram.com/p/ABC123/?img_index=2"),
+            Some("https://www.instagram.com/p/ABC123?img_index=2".to_string())
+        );
+    }
 }
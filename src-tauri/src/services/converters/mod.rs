@@ -3,11 +3,27 @@ pub mod bluesky;
 pub mod tiktok;
 pub mod registry;
 pub mod instagram;
+pub mod reddit;
+pub mod threads;
+pub mod pixiv;
+pub mod youtube;
+pub mod music;
+pub mod fediverse;
+pub mod normalize;
+pub mod privacy;
+pub mod declarative;
+pub mod conversion;
+pub mod options;
+pub mod custom;
+
+use conversion::{same_link, Conversion, ConversionError};
+use options::{ConverterOption, ConverterOptions};
+use std::collections::BTreeMap;
 
 /// Trait that all link converters must implement
 pub trait LinkConverterStrategy: Send + Sync {
     /// Returns the platform name this converter handles (e.g., "twitter", "bluesky")
-    fn platform_name(&self) -> &'static str;
+    fn platform_name(&self) -> &str;
     
     /// Checks if the given URL matches this platform's URL pattern
     fn matches(&self, url: &str) -> bool;
@@ -17,5 +33,92 @@ pub trait LinkConverterStrategy: Send + Sync {
     fn convert(&self, url: &str, converter: &str) -> Option<String>;
     
     /// Returns available converter options for this platform
-    fn available_converters(&self) -> Vec<&'static str>;
+    fn available_converters(&self) -> Vec<String>;
+
+    /// Modes the converter exposes, e.g. direct media links through a `d.` subdomain
+    fn supported_options(&self, _converter: &str) -> Vec<ConverterOption> {
+        Vec::new()
+    }
+
+    /// Converts a URL like `convert`, applying the options the converter supports
+    fn convert_with_options(&self, url: &str, converter: &str, _options: &ConverterOptions) -> Option<String> {
+        self.convert(url, converter)
+    }
+
+    /// Converts a link on one of this platform's known frontends back to the canonical platform URL
+    /// Returns None if the URL is not a known frontend link
+    fn canonicalize(&self, _url: &str) -> Option<String> {
+        None
+    }
+
+    /// Checks if the URL is already on the frontend of the given converter, for frontends that
+    /// cannot be told apart by their domain alone
+    fn is_converted(&self, _url: &str, _converter: &str) -> bool {
+        false
+    }
+
+    /// Checks if the converter keeps links on the platform itself, so vanilla links pass through
+    /// unchanged (e.g. "bsky" for Bluesky)
+    fn is_passthrough(&self, _converter: &str) -> bool {
+        false
+    }
+
+    /// Query parameters that identify what a link points at (e.g. `illust_id` for legacy pixiv links).
+    /// Conversion moves them into the converted link, so they are not carried over like other parameters.
+    fn content_params(&self) -> &[&str] {
+        &[]
+    }
+
+    /// Identifiers found in a link, e.g. `user` and `status` for a tweet
+    fn extract_ids(&self, _url: &str) -> BTreeMap<String, String> {
+        BTreeMap::new()
+    }
+
+    /// Converts a URL like `convert`, explaining why the link was left alone when it is not converted
+    fn try_convert(&self, url: &str, converter: &str, options: &ConverterOptions) -> Result<Conversion, ConversionError> {
+        let platform = self.platform_name().to_string();
+        if !self.available_converters().iter().any(|c| c.eq_ignore_ascii_case(converter)) {
+            return Err(ConversionError::UnknownConverter { platform, converter: converter.to_string() });
+        }
+
+        if !self.matches(url) {
+            return Err(if self.is_converted(url, converter) || self.canonicalize(url).is_some() {
+                ConversionError::AlreadyConverted { platform, converter: converter.to_string() }
+            } else {
+                ConversionError::NotSupported
+            });
+        }
+
+        // Converters return None for matching links they would leave as they are,
+        // e.g. YouTube watch links with the "watch" converter
+        match self.convert_with_options(url, converter, options).filter(|converted| !same_link(converted, url)) {
+            Some(converted) => Ok(Conversion {
+                platform,
+                converter: converter.to_lowercase(),
+                ids: self.extract_ids(url),
+                original: url.to_string(),
+                url: converted,
+                cleaned_only: false,
+            }),
+            None => Err(ConversionError::IdentityConversion { platform, converter: converter.to_string() }),
+        }
+    }
+}
+
+/// Builds a regex alternation matching any of the given domains
+pub(crate) fn domain_alternation<'a>(domains: impl IntoIterator<Item = &'a str>) -> String {
+    domains
+        .into_iter()
+        .map(regex::escape)
+        .collect::<Vec<_>>()
+        .join("|")
+}
+
+/// Lowercased host without scheme or trailing slash, e.g. "nitter.net".
+/// Returns None for blank values.
+pub(crate) fn normalize_host(value: &str) -> Option<String> {
+    let value = value.trim();
+    let without_scheme = value.split_once("://").map_or(value, |(_, rest)| rest);
+    let host = without_scheme.trim_end_matches('/').to_lowercase();
+    (!host.is_empty()).then_some(host)
 }
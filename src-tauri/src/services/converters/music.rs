@@ -0,0 +1,206 @@
+use super::LinkConverterStrategy;
+use crate::services::query_params::param_value;
+use regex::Regex;
+
+/// Converter names and the domains they use for tracks and albums
+const FRONTENDS: &[(&str, &str, &str)] = &[
+    ("songlink", "song.link", "album.link"),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Release {
+    Track,
+    Album,
+}
+
+pub struct MusicConverter {
+    spotify_regex: Regex,
+    apple_regex: Regex,
+    tidal_regex: Regex,
+    deezer_regex: Regex,
+    youtube_music_regex: Regex,
+    frontend_regex: Regex,
+}
+
+impl MusicConverter {
+    pub fn new() -> Self {
+        Self {
+            spotify_regex: Regex::new(
+                r"^(?:https?://)?open\.spotify\.com/(?:intl-[a-z]{2}/)?(track|album)/([A-Za-z0-9]+)"
+            ).unwrap(),
+            // Album links with an ?i=<track id> parameter point at a single track
+            apple_regex: Regex::new(
+                r"^(?:https?://)?(?:geo\.)?music\.apple\.com/([a-z]{2})/(album|song)/(?:([^/?#\s]+)/)?([0-9]+)"
+            ).unwrap(),
+            tidal_regex: Regex::new(
+                r"^(?:https?://)?(?:listen\.|www\.)?tidal\.com/(?:browse/)?(track|album)/([0-9]+)"
+            ).unwrap(),
+            deezer_regex: Regex::new(
+                r"^(?:https?://)?(?:www\.)?deezer\.com/(?:[a-z]{2}/)?(track|album)/([0-9]+)"
+            ).unwrap(),
+            // Albums on YouTube Music are playlists whose id starts with OLAK5uy_
+            youtube_music_regex: Regex::new(
+                r"^(?:https?://)?music\.youtube\.com/(watch|playlist)\?"
+            ).unwrap(),
+            // Universal links wrap the original URL in their path
+            frontend_regex: Regex::new(
+                r"^(?:https?://)?(?:song|album)\.link/(https?://\S+)"
+            ).unwrap(),
+        }
+    }
+
+    /// Kind of release and a clean URL for it on its original service
+    fn extract_release(&self, url: &str) -> Option<(Release, String)> {
+        if let Some(caps) = self.spotify_regex.captures(url) {
+            let kind = caps.get(1)?.as_str();
+            let id = caps.get(2)?.as_str();
+            return Some((release(kind), format!("https://open.spotify.com/{}/{}", kind, id)));
+        }
+
+        if let Some(caps) = self.apple_regex.captures(url) {
+            let country = caps.get(1)?.as_str();
+            let kind = caps.get(2)?.as_str();
+            let slug = caps.get(3).map_or(String::new(), |m| format!("{}/", m.as_str()));
+            let id = caps.get(4)?.as_str();
+            let base = format!("https://music.apple.com/{}/{}/{}{}", country, kind, slug, id);
+
+            return Some(match (kind, param_value(url, "i").filter(|i| !i.is_empty())) {
+                ("album", Some(track_id)) => (Release::Track, format!("{}?i={}", base, track_id)),
+                ("album", None) => (Release::Album, base),
+                _ => (Release::Track, base),
+            });
+        }
+
+        if let Some(caps) = self.tidal_regex.captures(url) {
+            let kind = caps.get(1)?.as_str();
+            let id = caps.get(2)?.as_str();
+            return Some((release(kind), format!("https://tidal.com/browse/{}/{}", kind, id)));
+        }
+
+        if let Some(caps) = self.deezer_regex.captures(url) {
+            let kind = caps.get(1)?.as_str();
+            let id = caps.get(2)?.as_str();
+            return Some((release(kind), format!("https://www.deezer.com/{}/{}", kind, id)));
+        }
+
+        let caps = self.youtube_music_regex.captures(url)?;
+        match caps.get(1)?.as_str() {
+            "watch" => {
+                let video_id = param_value(url, "v").filter(|v| !v.is_empty())?;
+                Some((Release::Track, format!("https://music.youtube.com/watch?v={}", video_id)))
+            }
+            _ => {
+                let list_id = param_value(url, "list").filter(|list| list.starts_with("OLAK5uy_"))?;
+                Some((Release::Album, format!("https://music.youtube.com/playlist?list={}", list_id)))
+            }
+        }
+    }
+}
+
+fn release(kind: &str) -> Release {
+    if kind == "album" {
+        Release::Album
+    } else {
+        Release::Track
+    }
+}
+
+impl LinkConverterStrategy for MusicConverter {
+    fn platform_name(&self) -> &str {
+        "music"
+    }
+
+    fn matches(&self, url: &str) -> bool {
+        self.extract_release(url).is_some()
+    }
+
+    fn convert(&self, url: &str, converter: &str) -> Option<String> {
+        let (kind, release_url) = self.extract_release(url)?;
+
+        let converter = converter.to_lowercase();
+        let (_, track_domain, album_domain) = FRONTENDS.iter().find(|(name, _, _)| *name == converter)?;
+
+        let domain = match kind {
+            Release::Track => track_domain,
+            Release::Album => album_domain,
+        };
+        Some(format!("https://{}/{}", domain, release_url))
+    }
+
+    fn available_converters(&self) -> Vec<String> {
+        FRONTENDS.iter().map(|(name, _, _)| name.to_string()).collect()
+    }
+
+    fn canonicalize(&self, url: &str) -> Option<String> {
+        let caps = self.frontend_regex.captures(url)?;
+        let (_, release_url) = self.extract_release(caps.get(1)?.as_str())?;
+        Some(release_url)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_music_matches() {
+        let converter = MusicConverter::new();
+
+        assert!(converter.matches("https://open.spotify.com/track/4cOdK2wGLETKBW3PvgPWqT?si=abc"));
+        assert!(converter.matches("https://open.spotify.com/intl-de/album/1DFixLWuPkv3KT3TnV35m3"));
+        assert!(converter.matches("https://music.apple.com/us/album/some-album/1440857781?i=1440857795"));
+        assert!(converter.matches("https://tidal.com/browse/track/12345678"));
+        assert!(converter.matches("https://www.deezer.com/en/album/302127"));
+        assert!(converter.matches("https://music.youtube.com/watch?v=dQw4w9WgXcQ&si=abc"));
+        assert!(converter.matches("https://music.youtube.com/playlist?list=OLAK5uy_abc123"));
+
+        assert!(!converter.matches("https://open.spotify.com/playlist/37i9dQZF1DXcBWIGoYBM5M"));
+        assert!(!converter.matches("https://music.youtube.com/playlist?list=PLabc123"));
+        assert!(!converter.matches("https://song.link/https://open.spotify.com/track/4cOdK2wGLETKBW3PvgPWqT"));
+    }
+
+    #[test]
+    fn test_music_convert() {
+        let converter = MusicConverter::new();
+
+        let cases = vec![
+            (
+                "https://open.spotify.com/track/4cOdK2wGLETKBW3PvgPWqT?si=abc",
+                "https://song.link/https://open.spotify.com/track/4cOdK2wGLETKBW3PvgPWqT",
+            ),
+            (
+                "https://open.spotify.com/intl-de/album/1DFixLWuPkv3KT3TnV35m3",
+                "https://album.link/https://open.spotify.com/album/1DFixLWuPkv3KT3TnV35m3",
+            ),
+            (
+                "https://music.apple.com/us/album/some-album/1440857781?i=1440857795&ls=1",
+                "https://song.link/https://music.apple.com/us/album/some-album/1440857781?i=1440857795",
+            ),
+            (
+                "https://music.apple.com/us/album/some-album/1440857781",
+                "https://album.link/https://music.apple.com/us/album/some-album/1440857781",
+            ),
+            ("https://listen.tidal.com/album/12345678", "https://album.link/https://tidal.com/browse/album/12345678"),
+            ("https://www.deezer.com/en/track/3135556", "https://song.link/https://www.deezer.com/track/3135556"),
+            (
+                "https://music.youtube.com/watch?v=dQw4w9WgXcQ&si=abc",
+                "https://song.link/https://music.youtube.com/watch?v=dQw4w9WgXcQ",
+            ),
+        ];
+
+        for (url, expected) in cases {
+            assert_eq!(converter.convert(url, "songlink"), Some(expected.to_string()), "for {}", url);
+        }
+    }
+
+    #[test]
+    fn test_music_canonicalize() {
+        let converter = MusicConverter::new();
+
+        assert_eq!(
+            converter.canonicalize("https://song.link/https://open.spotify.com/track/4cOdK2wGLETKBW3PvgPWqT"),
+            Some("https://open.spotify.com/track/4cOdK2wGLETKBW3PvgPWqT".to_string())
+        );
+        assert!(converter.canonicalize("https://open.spotify.com/track/4cOdK2wGLETKBW3PvgPWqT").is_none());
+    }
+}
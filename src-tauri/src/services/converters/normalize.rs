@@ -0,0 +1,154 @@
+use crate::services::query_params::split_url;
+use regex::Regex;
+use std::sync::RwLock;
+
+/// Rule names and patterns matched against the URL without its query and fragment.
+/// The replacement is the canonical desktop URL, which keeps the original query and fragment.
+const RULES: &[(&str, &str, &str)] = &[
+    // google.com/amp/s/<original host and path>
+    ("google-amp", r"^(?:https?://)?(?:www\.)?google\.[a-z.]+/amp/s/(\S+)$", "https://$1"),
+    // <publisher>.cdn.ampproject.org/c/s/<original host and path>
+    ("amp-cache", r"^(?:https?://)?[a-z0-9-]+\.cdn\.ampproject\.org/[cv]/s/(\S+)$", "https://$1"),
+    // en.m.wikipedia.org and the other Wikimedia projects
+    (
+        "wikimedia-mobile",
+        r"^(?:https?://)?([a-z0-9-]+)\.m\.(wikipedia|wiktionary|wikiquote|wikibooks|wikisource|wikinews|wikiversity|wikivoyage|wikimedia)\.org(\S*)$",
+        "https://$1.$2.org$3",
+    ),
+    ("youtube-mobile", r"^(?:https?://)?m\.youtube\.com(\S*)$", "https://www.youtube.com$1"),
+    ("facebook-mobile", r"^(?:https?://)?(?:m|mobile|touch|mbasic)\.facebook\.com(\S*)$", "https://www.facebook.com$1"),
+];
+
+struct NormalizationRule {
+    name: &'static str,
+    regex: Regex,
+    replacement: &'static str,
+}
+
+/// Turns AMP cache links and mobile subdomains into canonical desktop URLs before platforms see them.
+/// Every rule can be switched off on its own through the user's settings.
+pub struct LinkNormalizer {
+    rules: Vec<NormalizationRule>,
+    disabled: RwLock<Vec<String>>,
+}
+
+impl LinkNormalizer {
+    pub fn new() -> Self {
+        Self {
+            rules: RULES
+                .iter()
+                .map(|(name, pattern, replacement)| NormalizationRule {
+                    name,
+                    regex: Regex::new(pattern).unwrap(),
+                    replacement,
+                })
+                .collect(),
+            disabled: RwLock::new(Vec::new()),
+        }
+    }
+
+    /// Names of all rules, in the order they are applied
+    pub fn rule_names(&self) -> Vec<&str> {
+        self.rules.iter().map(|rule| rule.name).collect()
+    }
+
+    /// Replace the rules the user switched off
+    pub fn configure(&self, disabled_rules: &[String]) {
+        *self.disabled.write().unwrap() = disabled_rules.to_vec();
+    }
+
+    /// Apply every enabled rule in order, so an AMP link to a mobile page ends up on the desktop site.
+    /// Returns None if no rule applied.
+    pub fn normalize(&self, url: &str) -> Option<String> {
+        let disabled = self.disabled.read().unwrap();
+        let (base, query, fragment) = split_url(url);
+
+        let mut current = base.to_string();
+        let mut changed = false;
+        for rule in self.rules.iter().filter(|rule| !disabled.iter().any(|name| name == rule.name)) {
+            if rule.regex.is_match(&current) {
+                current = rule.regex.replace(&current, rule.replacement).into_owned();
+                changed = true;
+            }
+        }
+
+        if !changed {
+            return None;
+        }
+        if let Some(query) = query {
+            current.push('?');
+            current.push_str(query);
+        }
+        if let Some(fragment) = fragment {
+            current.push('#');
+            current.push_str(fragment);
+        }
+        Some(current)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_amp_links() {
+        let normalizer = LinkNormalizer::new();
+
+        let cases = vec![
+            (
+                "https://www.google.com/amp/s/www.example.com/news/story.amp.html",
+                "https://www.example.com/news/story.amp.html",
+            ),
+            (
+                "https://www-example-com.cdn.ampproject.org/c/s/www.example.com/news/story",
+                "https://www.example.com/news/story",
+            ),
+            (
+                "https://www.google.com/amp/s/en.m.wikipedia.org/wiki/Rust#History",
+                "https://en.wikipedia.org/wiki/Rust#History",
+            ),
+        ];
+
+        for (url, expected) in cases {
+            assert_eq!(normalizer.normalize(url), Some(expected.to_string()), "for {}", url);
+        }
+    }
+
+    #[test]
+    fn test_normalize_mobile_subdomains() {
+        let normalizer = LinkNormalizer::new();
+
+        let cases = vec![
+            ("https://en.m.wikipedia.org/wiki/Rust_(programming_language)", "https://en.wikipedia.org/wiki/Rust_(programming_language)"),
+            ("https://de.m.wiktionary.org/wiki/Haus", "https://de.wiktionary.org/wiki/Haus"),
+            ("https://m.youtube.com/@channel/videos", "https://www.youtube.com/@channel/videos"),
+            ("https://m.youtube.com/watch?v=abc&t=42#top", "https://www.youtube.com/watch?v=abc&t=42#top"),
+            ("https://mbasic.facebook.com/groups/123", "https://www.facebook.com/groups/123"),
+        ];
+
+        for (url, expected) in cases {
+            assert_eq!(normalizer.normalize(url), Some(expected.to_string()), "for {}", url);
+        }
+
+        assert!(normalizer.normalize("https://en.wikipedia.org/wiki/Rust").is_none());
+        assert!(normalizer.normalize("https://www.youtube.com/@channel").is_none());
+        assert!(normalizer.normalize("https://www.google.com/search?q=amp").is_none());
+    }
+
+    #[test]
+    fn test_disabled_rules() {
+        let normalizer = LinkNormalizer::new();
+        normalizer.configure(&["google-amp".to_string()]);
+
+        assert!(normalizer.normalize("https://www.google.com/amp/s/www.example.com/story").is_none());
+        assert!(normalizer.normalize("https://en.m.wikipedia.org/wiki/Rust").is_some());
+
+        // With the mobile rule off, AMP links still lead to the mobile page they wrap
+        normalizer.configure(&["wikimedia-mobile".to_string()]);
+        assert_eq!(
+            normalizer.normalize("https://www.google.com/amp/s/en.m.wikipedia.org/wiki/Rust"),
+            Some("https://en.m.wikipedia.org/wiki/Rust".to_string())
+        );
+    }
+}
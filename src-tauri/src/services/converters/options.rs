@@ -0,0 +1,105 @@
+use serde::{Deserialize, Serialize};
+
+/// A mode a frontend exposes through a subdomain or a path suffix
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ConverterOption {
+    /// Link straight to the media file (`d.` subdomain)
+    DirectMedia,
+    /// Show the media without the post around it (`g.` subdomain)
+    Gallery,
+    /// Embed only the post text (`t.` subdomain)
+    TextOnly,
+    /// Translate the post into the chosen language (`/<lang>` suffix)
+    Translate,
+}
+
+/// Options the user picked for a platform, applied on every conversion.
+/// Options the selected converter does not support are ignored.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq)]
+#[serde(default)]
+pub struct ConverterOptions {
+    pub direct_media: bool,
+    pub gallery: bool,
+    pub text_only: bool,
+    /// Language code to translate posts into, e.g. "en"
+    pub translate: Option<String>,
+}
+
+impl ConverterOptions {
+    /// Subdomain of the mode to use, out of the ones the converter supports.
+    /// Only one mode fits in the subdomain, so direct media wins over gallery, and gallery over text-only.
+    pub fn subdomain(&self, supported: &[ConverterOption]) -> Option<&'static str> {
+        [
+            (self.direct_media, ConverterOption::DirectMedia, "d"),
+            (self.gallery, ConverterOption::Gallery, "g"),
+            (self.text_only, ConverterOption::TextOnly, "t"),
+        ]
+        .into_iter()
+        .find(|(chosen, option, _)| *chosen && supported.contains(option))
+        .map(|(_, _, subdomain)| subdomain)
+    }
+
+    /// Language to translate into, if the converter supports translation and the code looks valid
+    pub fn language(&self, supported: &[ConverterOption]) -> Option<&str> {
+        if !supported.contains(&ConverterOption::Translate) {
+            return None;
+        }
+        self.translate.as_deref().filter(|language| is_language_code(language))
+    }
+}
+
+// Two-letter code with an optional region or script, e.g. "en", "pt-br" or "zh-hant"
+fn is_language_code(value: &str) -> bool {
+    let (language, region) = value.split_once('-').unwrap_or((value, ""));
+    language.len() == 2
+        && language.chars().all(|c| c.is_ascii_lowercase())
+        && (region.is_empty() || ((2..=4).contains(&region.len()) && region.chars().all(|c| c.is_ascii_lowercase())))
+}
+
+/// Options a converter supports, looked up in a table of converter names
+pub(crate) fn supported_options(table: &[(&str, &[ConverterOption])], converter: &str) -> Vec<ConverterOption> {
+    table
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case(converter))
+        .map(|(_, options)| options.to_vec())
+        .unwrap_or_default()
+}
+
+/// Frontend host with the subdomain of the chosen mode, e.g. "d.fxtwitter.com"
+pub(crate) fn frontend_host(domain: &str, options: &ConverterOptions, supported: &[ConverterOption]) -> String {
+    match options.subdomain(supported) {
+        Some(subdomain) => format!("{}.{}", subdomain, domain),
+        None => domain.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_converter_options() {
+        let all = [
+            ConverterOption::DirectMedia,
+            ConverterOption::Gallery,
+            ConverterOption::TextOnly,
+            ConverterOption::Translate,
+        ];
+        let options = ConverterOptions {
+            direct_media: true,
+            gallery: true,
+            translate: Some("pt-br".to_string()),
+            ..Default::default()
+        };
+
+        assert_eq!(frontend_host("fxtwitter.com", &options, &all), "d.fxtwitter.com");
+        assert_eq!(frontend_host("fxtwitter.com", &options, &[ConverterOption::Gallery]), "g.fxtwitter.com");
+        assert_eq!(frontend_host("bskye.app", &options, &[]), "bskye.app");
+        assert_eq!(options.language(&all), Some("pt-br"));
+        assert_eq!(options.language(&[ConverterOption::DirectMedia]), None);
+
+        let invalid = ConverterOptions { translate: Some("../x".to_string()), ..Default::default() };
+        assert_eq!(invalid.language(&all), None);
+    }
+}
@@ -0,0 +1,178 @@
+use super::conversion::extracted_ids;
+use super::{domain_alternation, LinkConverterStrategy};
+use crate::services::query_params::param_value;
+use regex::Regex;
+use std::collections::BTreeMap;
+
+/// Converter names and the frontend domains they map to
+const FRONTENDS: &[(&str, &str)] = &[
+    ("phixiv", "phixiv.net"),
+    ("ppxiv", "ppxiv.net"),
+];
+
+/// Optional locale prefix such as `/en`
+const LOCALE: &str = r"(?:/([a-z]{2}(?:-[a-z]{2,4})?))?";
+
+pub struct PixivConverter {
+    artwork_regex: Regex,
+    legacy_regex: Regex,
+    frontend_regex: Regex,
+}
+
+impl PixivConverter {
+    pub fn new() -> Self {
+        Self {
+            // Artwork pages, with a 1-based page index in the fragment (e.g. #2)
+            artwork_regex: Regex::new(&format!(
+                r"^(?:https?://)?(?:www\.)?pixiv\.net{}/artworks/([0-9]+)(?:/?(?:\?[^#\s]*)?#([1-9][0-9]*))?",
+                LOCALE
+            )).unwrap(),
+            // Legacy member_illust.php links, where the page parameter is 0-based
+            legacy_regex: Regex::new(
+                r"^(?:https?://)?(?:www\.)?pixiv\.net/member_illust\.php\?"
+            ).unwrap(),
+            // Frontends put the 1-based page index after the artwork id
+            frontend_regex: Regex::new(&format!(
+                r"^(?:https?://)?(?:www\.)?(?:{}){}/artworks/([0-9]+)(?:/([1-9][0-9]*))?",
+                domain_alternation(FRONTENDS.iter().map(|(_, domain)| *domain)),
+                LOCALE
+            )).unwrap(),
+        }
+    }
+
+    /// Locale, artwork id and 1-based page index of a pixiv link
+    fn extract_artwork(&self, url: &str) -> Option<(Option<String>, String, Option<u32>)> {
+        if let Some(caps) = self.artwork_regex.captures(url) {
+            let locale = caps.get(1).map(|m| m.as_str().to_string());
+            let artwork_id = caps.get(2)?.as_str().to_string();
+            let page = caps.get(3).and_then(|m| m.as_str().parse().ok());
+            return Some((locale, artwork_id, page));
+        }
+
+        if !self.legacy_regex.is_match(url) {
+            return None;
+        }
+
+        let artwork_id = param_value(url, "illust_id").filter(|id| !id.is_empty() && id.chars().all(|c| c.is_ascii_digit()))?;
+        let page = param_value(url, "page").and_then(|page| page.parse::<u32>().ok()).map(|page| page + 1);
+
+        Some((None, artwork_id.to_string(), page))
+    }
+}
+
+fn artwork_path(locale: Option<&str>, artwork_id: &str) -> String {
+    match locale {
+        Some(locale) => format!("/{}/artworks/{}", locale, artwork_id),
+        None => format!("/artworks/{}", artwork_id),
+    }
+}
+
+impl LinkConverterStrategy for PixivConverter {
+    fn platform_name(&self) -> &str {
+        "pixiv"
+    }
+
+    fn matches(&self, url: &str) -> bool {
+        self.extract_artwork(url).is_some()
+    }
+
+    fn convert(&self, url: &str, converter: &str) -> Option<String> {
+        let (locale, artwork_id, page) = self.extract_artwork(url)?;
+
+        let converter = converter.to_lowercase();
+        let (_, converter_domain) = FRONTENDS.iter().find(|(name, _)| *name == converter)?;
+
+        let mut converted = format!("https://{}{}", converter_domain, artwork_path(locale.as_deref(), &artwork_id));
+        if let Some(page) = page {
+            converted.push_str(&format!("/{}", page));
+        }
+
+        Some(converted)
+    }
+
+    fn content_params(&self) -> &[&str] {
+        &["illust_id", "page"]
+    }
+
+    fn extract_ids(&self, url: &str) -> BTreeMap<String, String> {
+        let Some((_, artwork_id, page)) = self.extract_artwork(url) else {
+            return BTreeMap::new();
+        };
+        let page = page.map(|page| page.to_string());
+        extracted_ids([("artwork", Some(artwork_id.as_str())), ("page", page.as_deref())])
+    }
+
+    fn available_converters(&self) -> Vec<String> {
+        FRONTENDS.iter().map(|(name, _)| name.to_string()).collect()
+    }
+
+    fn canonicalize(&self, url: &str) -> Option<String> {
+        let caps = self.frontend_regex.captures(url)?;
+        let locale = caps.get(1).map(|m| m.as_str());
+        let artwork_id = caps.get(2)?.as_str();
+
+        let mut canonical = format!("https://www.pixiv.net{}", artwork_path(locale, artwork_id));
+        if let Some(page) = caps.get(3) {
+            canonical.push_str(&format!("#{}", page.as_str()));
+        }
+
+        Some(canonical)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pixiv_matches() {
+        let converter = PixivConverter::new();
+
+        assert!(converter.matches("https://www.pixiv.net/artworks/123456"));
+        assert!(converter.matches("https://www.pixiv.net/en/artworks/123456"));
+        assert!(converter.matches("https://pixiv.net/member_illust.php?mode=medium&illust_id=123456"));
+
+        assert!(!converter.matches("https://phixiv.net/artworks/123456"));
+        assert!(!converter.matches("https://www.pixiv.net/users/123456"));
+        assert!(!converter.matches("https://www.pixiv.net/member_illust.php?mode=medium"));
+    }
+
+    #[test]
+    fn test_pixiv_convert() {
+        let converter = PixivConverter::new();
+
+        let cases = vec![
+            ("https://www.pixiv.net/artworks/123456", "https://phixiv.net/artworks/123456"),
+            ("https://www.pixiv.net/en/artworks/123456", "https://phixiv.net/en/artworks/123456"),
+            ("https://www.pixiv.net/artworks/123456#3", "https://phixiv.net/artworks/123456/3"),
+            ("https://www.pixiv.net/en/artworks/123456?foo=1#2", "https://phixiv.net/en/artworks/123456/2"),
+            ("https://www.pixiv.net/member_illust.php?mode=medium&illust_id=123456", "https://phixiv.net/artworks/123456"),
+            ("https://www.pixiv.net/member_illust.php?mode=manga_big&illust_id=123456&page=0", "https://phixiv.net/artworks/123456/1"),
+        ];
+
+        for (url, expected) in cases {
+            assert_eq!(converter.convert(url, "phixiv"), Some(expected.to_string()), "for {}", url);
+        }
+
+        assert_eq!(
+            converter.convert("https://www.pixiv.net/artworks/123456", "ppxiv"),
+            Some("https://ppxiv.net/artworks/123456".to_string())
+        );
+        assert!(converter.convert("https://phixiv.net/artworks/123456", "ppxiv").is_none());
+    }
+
+    #[test]
+    fn test_pixiv_canonicalize() {
+        let converter = PixivConverter::new();
+
+        assert_eq!(
+            converter.canonicalize("https://phixiv.net/en/artworks/123456/2"),
+            Some("https://www.pixiv.net/en/artworks/123456#2".to_string())
+        );
+        assert_eq!(
+            converter.canonicalize("https://www.ppxiv.net/artworks/123456"),
+            Some("https://www.pixiv.net/artworks/123456".to_string())
+        );
+        assert!(converter.canonicalize("https://www.pixiv.net/artworks/123456").is_none());
+    }
+}
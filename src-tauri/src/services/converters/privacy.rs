@@ -0,0 +1,242 @@
+use super::{domain_alternation, normalize_host, LinkConverterStrategy};
+use crate::services::query_params::param_value;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::RwLock;
+
+/// Platform, converter name, canonical host, the pattern for original links, whose first group is
+/// the path the frontend mirrors, and the query parameters that identify content and move along with it
+const FRONTENDS: &[(&str, &str, &str, &str, &[&str])] = &[
+    ("twitter", "nitter", "x.com", r"^(?:https?://)?(?:www\.|mobile\.)?(?:twitter|x)\.com(/[^?#\s]+)", &[]),
+    ("reddit", "redlib", "www.reddit.com", r"^(?:https?://)?(?:(?:www|old|new|np|m)\.)?reddit\.com(/(?:r|u|user)/[^?#\s]+)", &[]),
+    ("tiktok", "proxitok", "www.tiktok.com", r"^(?:https?://)?(?:www\.|m\.)?tiktok\.com(/@[^?#\s]+)", &[]),
+    (
+        "youtube",
+        "invidious",
+        "www.youtube.com",
+        r"^(?:https?://)?(?:www\.|m\.)?youtube\.com(/(?:watch|shorts/|live/|playlist|channel/|@)[^?#\s]*)",
+        &["v", "list", "t"],
+    ),
+];
+
+/// youtu.be links have no path Invidious mirrors, so they become watch links
+const YOUTUBE_SHORT_LINK: &str = r"^(?:https?://)?(?:www\.)?youtu\.be/([A-Za-z0-9_-]{11})(?:[/?#]|$)";
+
+/// How an instance is picked for each conversion
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum InstanceSelection {
+    /// Always the first instance in the list
+    #[default]
+    Fixed,
+    Random,
+    RoundRobin,
+}
+
+#[derive(Default)]
+struct InstancePool {
+    hosts: Vec<String>,
+    selection: InstanceSelection,
+    instance_regex: Option<Regex>,
+}
+
+/// Rewrites links to a read-only privacy frontend (Nitter, Redlib, ...) hosted on user-configured instances
+pub struct PrivacyFrontendConverter {
+    platform: &'static str,
+    name: &'static str,
+    canonical_host: &'static str,
+    vanilla_regex: Regex,
+    params: &'static [&'static str],
+    short_link_regex: Option<Regex>,
+    pool: RwLock<InstancePool>,
+    next: AtomicUsize,
+}
+
+impl PrivacyFrontendConverter {
+    /// One converter for every supported privacy frontend
+    pub fn all() -> Vec<Self> {
+        FRONTENDS
+            .iter()
+            .map(|(platform, name, canonical_host, pattern, params)| Self {
+                platform,
+                name,
+                canonical_host,
+                vanilla_regex: Regex::new(pattern).unwrap(),
+                params,
+                short_link_regex: (*platform == "youtube").then(|| Regex::new(YOUTUBE_SHORT_LINK).unwrap()),
+                pool: RwLock::new(InstancePool::default()),
+                next: AtomicUsize::new(0),
+            })
+            .collect()
+    }
+
+    /// Converter name of the frontend, e.g. "nitter"
+    pub fn name(&self) -> &str {
+        self.name
+    }
+
+    /// Replace the instances and how they are picked. Blank entries are ignored.
+    pub fn configure(&self, instances: &[String], selection: InstanceSelection) {
+        let hosts: Vec<String> = instances.iter().filter_map(|i| normalize_host(i)).collect();
+        let instance_regex = (!hosts.is_empty()).then(|| {
+            Regex::new(&format!(
+                r"^(?:https?://)?(?:www\.)?(?:{})(/\S+)",
+                domain_alternation(hosts.iter().map(String::as_str))
+            )).unwrap()
+        });
+
+        *self.pool.write().unwrap() = InstancePool {
+            hosts,
+            selection,
+            instance_regex,
+        };
+        self.next.store(0, Ordering::Relaxed);
+    }
+
+    fn pick_instance(&self, pool: &InstancePool) -> Option<String> {
+        if pool.hosts.is_empty() {
+            return None;
+        }
+
+        let index = match pool.selection {
+            InstanceSelection::Fixed => 0,
+            InstanceSelection::Random => RandomState::new().build_hasher().finish() as usize % pool.hosts.len(),
+            InstanceSelection::RoundRobin => self.next.fetch_add(1, Ordering::Relaxed) % pool.hosts.len(),
+        };
+        Some(pool.hosts[index].clone())
+    }
+
+    /// Path on the frontend with the parameters that identify content, e.g. "/watch?v=dQw4w9WgXcQ&t=42"
+    fn frontend_path(&self, url: &str) -> Option<String> {
+        let (path, mut params) = if let Some(caps) = self.vanilla_regex.captures(url) {
+            (caps.get(1)?.as_str().to_string(), Vec::new())
+        } else {
+            let caps = self.short_link_regex.as_ref()?.captures(url)?;
+            ("/watch".to_string(), vec![format!("v={}", caps.get(1)?.as_str())])
+        };
+
+        for name in self.params {
+            if params.iter().any(|p| p.starts_with(&format!("{}=", name))) {
+                continue;
+            }
+            if let Some(value) = param_value(url, name).filter(|value| !value.is_empty()) {
+                params.push(format!("{}={}", name, value));
+            }
+        }
+
+        if params.is_empty() {
+            return Some(path);
+        }
+        Some(format!("{}?{}", path, params.join("&")))
+    }
+}
+
+impl LinkConverterStrategy for PrivacyFrontendConverter {
+    fn platform_name(&self) -> &str {
+        self.platform
+    }
+
+    fn matches(&self, url: &str) -> bool {
+        self.frontend_path(url).is_some()
+    }
+
+    fn convert(&self, url: &str, converter: &str) -> Option<String> {
+        if !converter.eq_ignore_ascii_case(self.name) {
+            return None;
+        }
+
+        let path = self.frontend_path(url)?;
+        let instance = self.pick_instance(&self.pool.read().unwrap())?;
+        Some(format!("https://{}{}", instance, path))
+    }
+
+    fn available_converters(&self) -> Vec<String> {
+        vec![self.name.to_string()]
+    }
+
+    fn canonicalize(&self, url: &str) -> Option<String> {
+        let pool = self.pool.read().unwrap();
+        let caps = pool.instance_regex.as_ref()?.captures(url)?;
+
+        Some(format!("https://{}{}", self.canonical_host, caps.get(1)?.as_str()))
+    }
+
+    fn is_converted(&self, url: &str, converter: &str) -> bool {
+        converter.eq_ignore_ascii_case(self.name)
+            && self.pool.read().unwrap().instance_regex.as_ref().is_some_and(|regex| regex.is_match(url))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn frontend(name: &str) -> PrivacyFrontendConverter {
+        PrivacyFrontendConverter::all().into_iter().find(|f| f.name() == name).unwrap()
+    }
+
+    #[test]
+    fn test_privacy_frontend_convert() {
+        let nitter = frontend("nitter");
+        nitter.configure(&["https://nitter.example.org/".to_string()], InstanceSelection::Fixed);
+
+        assert_eq!(
+            nitter.convert("https://x.com/user/status/123", "nitter"),
+            Some("https://nitter.example.org/user/status/123".to_string())
+        );
+        assert!(nitter.convert("https://x.com/user/status/123", "fxtwitter").is_none());
+
+        let invidious = frontend("invidious");
+        invidious.configure(&["yt.example.org".to_string()], InstanceSelection::Fixed);
+
+        assert_eq!(
+            invidious.convert("https://www.youtube.com/watch?v=dQw4w9WgXcQ&si=abc&list=PL1&t=42", "invidious"),
+            Some("https://yt.example.org/watch?v=dQw4w9WgXcQ&list=PL1&t=42".to_string())
+        );
+        assert_eq!(
+            invidious.convert("https://youtu.be/dQw4w9WgXcQ?t=42", "invidious"),
+            Some("https://yt.example.org/watch?v=dQw4w9WgXcQ&t=42".to_string())
+        );
+
+        let redlib = frontend("redlib");
+        assert!(redlib.convert("https://www.reddit.com/r/rust/comments/abc", "redlib").is_none());
+    }
+
+    #[test]
+    fn test_instance_selection() {
+        let redlib = frontend("redlib");
+        let instances = vec!["a.example".to_string(), "b.example".to_string()];
+        let url = "https://old.reddit.com/r/rust/comments/abc/title/";
+
+        redlib.configure(&instances, InstanceSelection::RoundRobin);
+        let picked: Vec<String> = (0..3).filter_map(|_| redlib.convert(url, "redlib")).collect();
+        assert_eq!(picked, vec![
+            "https://a.example/r/rust/comments/abc/title/",
+            "https://b.example/r/rust/comments/abc/title/",
+            "https://a.example/r/rust/comments/abc/title/",
+        ]);
+
+        redlib.configure(&instances, InstanceSelection::Random);
+        for _ in 0..10 {
+            let converted = redlib.convert(url, "redlib").unwrap();
+            assert!(converted.starts_with("https://a.example/") || converted.starts_with("https://b.example/"));
+        }
+    }
+
+    #[test]
+    fn test_privacy_frontend_canonicalize() {
+        let proxitok = frontend("proxitok");
+        proxitok.configure(&["tok.example.org".to_string()], InstanceSelection::Fixed);
+
+        assert_eq!(
+            proxitok.canonicalize("https://tok.example.org/@user/video/123?lang=en"),
+            Some("https://www.tiktok.com/@user/video/123?lang=en".to_string())
+        );
+        assert!(proxitok.is_converted("https://tok.example.org/@user/video/123", "proxitok"));
+        assert!(!proxitok.is_converted("https://tok.example.org/@user/video/123", "tntok"));
+        assert!(proxitok.canonicalize("https://www.tiktok.com/@user/video/123").is_none());
+    }
+}
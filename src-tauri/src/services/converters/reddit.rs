@@ -0,0 +1,220 @@
+use super::conversion::extracted_ids;
+use super::{domain_alternation, LinkConverterStrategy};
+use regex::Regex;
+use std::collections::BTreeMap;
+
+/// Converter names and the frontend domains they map to
+const FRONTENDS: &[(&str, &str)] = &[
+    ("rxddit", "rxddit.com"),
+    ("vxreddit", "vxreddit.com"),
+];
+
+pub struct RedditConverter {
+    permalink_regex: Regex,
+    share_regex: Regex,
+    short_regex: Regex,
+    frontend_regex: Regex,
+}
+
+impl RedditConverter {
+    pub fn new() -> Self {
+        Self {
+            // Post and comment permalinks on vanilla reddit.com and its old/new/np/m subdomains
+            permalink_regex: Regex::new(
+                r"^(?:https?://)?(?:(?:www|old|new|np|m)\.)?reddit\.com/((?:r|u|user)/[^/?#\s]+/)?comments/([a-z0-9]+)(?:/([^/?#\s]+))?(?:/([a-z0-9]+))?"
+            ).unwrap(),
+            // Share links generated by the app: reddit.com/r/<sub>/s/<code>
+            share_regex: Regex::new(
+                r"^(?:https?://)?(?:(?:www|old|new|np|m)\.)?reddit\.com/r/([^/?#\s]+)/s/([A-Za-z0-9]+)"
+            ).unwrap(),
+            // Short links: redd.it/<id>
+            short_regex: Regex::new(
+                r"^(?:https?://)?(?:www\.)?redd\.it/([a-z0-9]+)"
+            ).unwrap(),
+            // Frontends mirror reddit's paths, so only the domain needs recognising
+            frontend_regex: Regex::new(&format!(
+                r"^(?:https?://)?(?:(?:www|old|new|np|m)\.)?(?:{})(/\S*)",
+                domain_alternation(FRONTENDS.iter().map(|(_, domain)| *domain))
+            )).unwrap(),
+        }
+    }
+
+    fn extract_path(&self, url: &str) -> Option<String> {
+        if let Some(caps) = self.permalink_regex.captures(url) {
+            let prefix = caps.get(1).map_or("", |m| m.as_str());
+            let post_id = caps.get(2)?.as_str();
+
+            let mut path = format!("/{}comments/{}", prefix, post_id);
+            if let Some(slug) = caps.get(3) {
+                path.push_str(&format!("/{}", slug.as_str()));
+                if let Some(comment_id) = caps.get(4) {
+                    path.push_str(&format!("/{}", comment_id.as_str()));
+                }
+            }
+            return Some(path);
+        }
+
+        if let Some(caps) = self.share_regex.captures(url) {
+            let subreddit = caps.get(1)?.as_str();
+            let share_id = caps.get(2)?.as_str();
+            return Some(format!("/r/{}/s/{}", subreddit, share_id));
+        }
+
+        let caps = self.short_regex.captures(url)?;
+        let post_id = caps.get(1)?.as_str();
+        Some(format!("/comments/{}", post_id))
+    }
+}
+
+impl LinkConverterStrategy for RedditConverter {
+    fn platform_name(&self) -> &str {
+        "reddit"
+    }
+
+    fn matches(&self, url: &str) -> bool {
+        self.permalink_regex.is_match(url)
+            || self.share_regex.is_match(url)
+            || self.short_regex.is_match(url)
+    }
+
+    fn convert(&self, url: &str, converter: &str) -> Option<String> {
+        if !self.matches(url) {
+            return None;
+        }
+
+        let path = self.extract_path(url)?;
+
+        let converter = converter.to_lowercase();
+        let (_, converter_domain) = FRONTENDS.iter().find(|(name, _)| *name == converter)?;
+
+        Some(format!("https://{}{}", converter_domain, path))
+    }
+
+    fn extract_ids(&self, url: &str) -> BTreeMap<String, String> {
+        if let Some(caps) = self.permalink_regex.captures(url) {
+            return extracted_ids([
+                ("post", caps.get(2).map(|m| m.as_str())),
+                ("comment", caps.get(4).map(|m| m.as_str())),
+            ]);
+        }
+
+        if let Some(caps) = self.share_regex.captures(url) {
+            return extracted_ids([
+                ("subreddit", caps.get(1).map(|m| m.as_str())),
+                ("share", caps.get(2).map(|m| m.as_str())),
+            ]);
+        }
+
+        let post = self.short_regex.captures(url).and_then(|caps| caps.get(1));
+        extracted_ids([("post", post.map(|m| m.as_str()))])
+    }
+
+    fn available_converters(&self) -> Vec<String> {
+        FRONTENDS.iter().map(|(name, _)| name.to_string()).collect()
+    }
+
+    fn canonicalize(&self, url: &str) -> Option<String> {
+        let caps = self.frontend_regex.captures(url)?;
+        let vanilla_url = format!("https://www.reddit.com{}", caps.get(1)?.as_str());
+
+        let path = self.extract_path(&vanilla_url)?;
+        Some(format!("https://www.reddit.com{}", path))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reddit_matches() {
+        let converter = RedditConverter::new();
+
+        assert!(converter.matches("https://www.reddit.com/r/rust/comments/abc123/some_title/"));
+        assert!(converter.matches("https://old.reddit.com/r/rust/comments/abc123/some_title/def456/"));
+        assert!(converter.matches("https://new.reddit.com/r/rust/comments/abc123"));
+        assert!(converter.matches("https://np.reddit.com/r/rust/comments/abc123/some_title/"));
+        assert!(converter.matches("https://www.reddit.com/user/someone/comments/abc123/title/"));
+        assert!(converter.matches("https://www.reddit.com/r/rust/s/AbCdEf123"));
+        assert!(converter.matches("https://redd.it/abc123"));
+
+        // Should not match already-converted URLs
+        assert!(!converter.matches("https://rxddit.com/r/rust/comments/abc123/some_title/"));
+        assert!(!converter.matches("https://vxreddit.com/r/rust/comments/abc123/some_title/"));
+        assert!(!converter.matches("https://www.reddit.com/r/rust/"));
+    }
+
+    #[test]
+    fn test_reddit_convert() {
+        let converter = RedditConverter::new();
+
+        let result = converter.convert(
+            "https://www.reddit.com/r/rust/comments/abc123/some_title/?utm_source=share",
+            "rxddit",
+        );
+        assert_eq!(result, Some("https://rxddit.com/r/rust/comments/abc123/some_title".to_string()));
+
+        // Already converted should return None
+        assert!(converter.convert("https://rxddit.com/r/rust/comments/abc123/", "vxreddit").is_none());
+    }
+
+    #[test]
+    fn test_comment_permalink() {
+        let converter = RedditConverter::new();
+
+        let result = converter.convert(
+            "https://old.reddit.com/r/rust/comments/abc123/some_title/def456/",
+            "vxreddit",
+        );
+        assert_eq!(
+            result,
+            Some("https://vxreddit.com/r/rust/comments/abc123/some_title/def456".to_string())
+        );
+    }
+
+    #[test]
+    fn test_short_and_share_links() {
+        let converter = RedditConverter::new();
+
+        assert_eq!(
+            converter.convert("https://redd.it/abc123", "rxddit"),
+            Some("https://rxddit.com/comments/abc123".to_string())
+        );
+        assert_eq!(
+            converter.convert("https://www.reddit.com/r/rust/s/AbCdEf123", "rxddit"),
+            Some("https://rxddit.com/r/rust/s/AbCdEf123".to_string())
+        );
+    }
+
+    #[test]
+    fn test_different_converter_outputs() {
+        let converter = RedditConverter::new();
+        let vanilla_url = "https://www.reddit.com/r/rust/comments/abc123/some_title/";
+
+        let expected = vec![
+            ("rxddit", "https://rxddit.com/r/rust/comments/abc123/some_title"),
+            ("vxreddit", "https://vxreddit.com/r/rust/comments/abc123/some_title"),
+        ];
+
+        for (converter_type, expected_url) in expected {
+            let result = converter.convert(vanilla_url, converter_type);
+            assert_eq!(result, Some(expected_url.to_string()));
+        }
+    }
+
+    #[test]
+    fn test_reddit_canonicalize() {
+        let converter = RedditConverter::new();
+
+        assert_eq!(
+            converter.canonicalize("https://rxddit.com/r/rust/comments/abc123/some_title/"),
+            Some("https://www.reddit.com/r/rust/comments/abc123/some_title".to_string())
+        );
+        assert_eq!(
+            converter.canonicalize("https://old.vxreddit.com/r/rust/s/AbCdEf123"),
+            Some("https://www.reddit.com/r/rust/s/AbCdEf123".to_string())
+        );
+        assert!(converter.canonicalize("https://rxddit.com/r/rust/").is_none());
+        assert!(converter.canonicalize("https://www.reddit.com/r/rust/comments/abc123/").is_none());
+    }
+}
@@ -1,35 +1,177 @@
-use super::{LinkConverterStrategy, twitter::TwitterConverter, bluesky::BlueSkyConverter, tiktok::TikTokConverter, instagram::InstagramConverter};
+use super::{LinkConverterStrategy, twitter::TwitterConverter, bluesky::BlueSkyConverter, tiktok::TikTokConverter, instagram::InstagramConverter, reddit::RedditConverter, threads::ThreadsConverter, pixiv::PixivConverter, youtube::YouTubeConverter, music::MusicConverter, fediverse::FediverseConverter, normalize::LinkNormalizer, privacy::PrivacyFrontendConverter, custom::{CustomFrontend, CustomFrontendConverter}};
+use super::conversion::{Conversion, ConversionError};
+use super::declarative::{ConverterDefinition, DeclarativeConverter};
+use super::options::{ConverterOption, ConverterOptions};
+use std::collections::BTreeMap;
 use std::sync::Arc;
 
 /// Registry that manages all available link converter strategies
 pub struct ConverterRegistry {
     converters: Vec<Arc<dyn LinkConverterStrategy>>,
+    fediverse: Arc<FediverseConverter>,
+    normalizer: LinkNormalizer,
+    privacy: Vec<Arc<PrivacyFrontendConverter>>,
+    custom: Vec<Arc<CustomFrontendConverter>>,
 }
 
 impl ConverterRegistry {
     /// Creates a new registry with default converters
     pub fn new() -> Self {
+        let fediverse = Arc::new(FediverseConverter::new());
+        let privacy: Vec<Arc<PrivacyFrontendConverter>> = PrivacyFrontendConverter::all().into_iter().map(Arc::new).collect();
+
+        let mut converters: Vec<Arc<dyn LinkConverterStrategy>> = vec![
+            Arc::new(TwitterConverter::new()),
+            Arc::new(BlueSkyConverter::new()),
+            Arc::new(TikTokConverter::new()),
+            Arc::new(InstagramConverter::new()),
+            Arc::new(RedditConverter::new()),
+            Arc::new(ThreadsConverter::new()),
+            Arc::new(PixivConverter::new()),
+            Arc::new(YouTubeConverter::new()),
+            Arc::new(MusicConverter::new()),
+            fediverse.clone(),
+        ];
+        // Self-hosted frontends rely on the ids found by the built-in converter of their platform
+        let custom: Vec<Arc<CustomFrontendConverter>> = converters
+            .iter()
+            .map(|base| Arc::new(CustomFrontendConverter::new(base.clone())))
+            .collect();
+
+        converters.extend(privacy.iter().map(|frontend| frontend.clone() as Arc<dyn LinkConverterStrategy>));
+        converters.extend(custom.iter().map(|frontend| frontend.clone() as Arc<dyn LinkConverterStrategy>));
+
         Self {
-            converters: vec![
-                Arc::new(TwitterConverter::new()),
-                Arc::new(BlueSkyConverter::new()),
-                Arc::new(TikTokConverter::new()),
-                Arc::new(InstagramConverter::new()),
-            ],
+            converters,
+            fediverse,
+            normalizer: LinkNormalizer::new(),
+            privacy,
+            custom,
         }
     }
 
+    /// Creates a registry with default converters plus user definitions.
+    /// Definitions that fail to compile or target a platform without settings are skipped and
+    /// returned as errors.
+    pub fn with_definitions(definitions: &[ConverterDefinition]) -> (Self, Vec<String>) {
+        let mut registry = Self::new();
+        let platforms = registry.platforms();
+        let (supported, unsupported): (Vec<ConverterDefinition>, Vec<ConverterDefinition>) = definitions
+            .iter()
+            .cloned()
+            .partition(|definition| platforms.contains(&definition.platform.to_lowercase().as_str()));
+
+        let mut errors: Vec<String> = unsupported
+            .iter()
+            .map(|definition| format!("Converter '{}': unknown platform '{}'", definition.name, definition.platform))
+            .collect();
+        let (declarative, compile_errors) = DeclarativeConverter::from_definitions(&supported);
+        errors.extend(compile_errors);
+
+        // Overrides go to the front, so register them backwards to keep the file's order
+        for converter in declarative.into_iter().rev() {
+            registry.register_override(Arc::new(converter));
+        }
+
+        (registry, errors)
+    }
+
     /// Register a new converter strategy
     pub fn register(&mut self, converter: Arc<dyn LinkConverterStrategy>) {
         self.converters.push(converter);
     }
 
+    /// Register a converter strategy that takes precedence over existing ones for its platform
+    pub fn register_override(&mut self, converter: Arc<dyn LinkConverterStrategy>) {
+        self.converters.insert(0, converter);
+    }
+
     /// Convert a URL using a specific platform and converter
     pub fn convert(&self, url: &str, platform: &str, converter: &str) -> Option<String> {
         self.converters
             .iter()
-            .find(|c| c.platform_name() == platform)
-            .and_then(|c| c.convert(url, converter))
+            .filter(|c| c.platform_name() == platform)
+            .find_map(|c| c.convert(url, converter))
+    }
+
+    /// Convert a URL using a specific platform and converter, explaining why the link was left alone
+    /// when it is not converted. Like `convert`, strategies registered first take precedence.
+    pub fn try_convert(
+        &self,
+        url: &str,
+        platform: &str,
+        converter: &str,
+        options: &ConverterOptions,
+    ) -> Result<Conversion, ConversionError> {
+        let mut error = ConversionError::NotSupported;
+        let mut offered = false;
+
+        for strategy in self.converters.iter().filter(|c| c.platform_name() == platform) {
+            if !strategy.available_converters().iter().any(|name| name.eq_ignore_ascii_case(converter)) {
+                continue;
+            }
+            offered = true;
+
+            match strategy.try_convert(url, converter, options) {
+                Ok(mut conversion) => {
+                    // User definitions may not know the ids the built-in strategies find
+                    if conversion.ids.is_empty() {
+                        conversion.ids = self.extract_ids(url, platform);
+                    }
+                    return Ok(conversion);
+                }
+                Err(e) if !error.is_recognized() => error = e,
+                Err(_) => {}
+            }
+        }
+
+        if !offered && self.platforms().contains(&platform) {
+            return Err(ConversionError::UnknownConverter {
+                platform: platform.to_string(),
+                converter: converter.to_string(),
+            });
+        }
+        Err(error)
+    }
+
+    /// Modes the converter exposes for the platform. Like `convert`, the strategy registered first
+    /// for the converter decides.
+    pub fn supported_options(&self, platform: &str, converter: &str) -> Vec<ConverterOption> {
+        self.converters
+            .iter()
+            .filter(|c| c.platform_name() == platform)
+            .find(|c| c.available_converters().iter().any(|name| name.eq_ignore_ascii_case(converter)))
+            .map(|c| c.supported_options(converter))
+            .unwrap_or_default()
+    }
+
+    /// Checks if the converter keeps vanilla links of the platform unchanged.
+    /// Like `convert`, the strategy registered first for the converter decides.
+    pub fn is_passthrough(&self, platform: &str, converter: &str) -> bool {
+        self.converters
+            .iter()
+            .filter(|c| c.platform_name() == platform)
+            .find(|c| c.available_converters().iter().any(|name| name.eq_ignore_ascii_case(converter)))
+            .is_some_and(|c| c.is_passthrough(converter))
+    }
+
+    /// Query parameters the platform's strategies move into converted links
+    pub fn content_params(&self, platform: &str) -> Vec<&str> {
+        self.converters
+            .iter()
+            .filter(|c| c.platform_name() == platform)
+            .flat_map(|c| c.content_params().iter().copied())
+            .collect()
+    }
+
+    /// Identifiers found in a link by the first strategy of the platform that recognises them
+    pub fn extract_ids(&self, url: &str, platform: &str) -> BTreeMap<String, String> {
+        self.converters
+            .iter()
+            .filter(|c| c.platform_name() == platform)
+            .map(|c| c.extract_ids(url))
+            .find(|ids| !ids.is_empty())
+            .unwrap_or_default()
     }
 
     /// Try to convert a URL by checking all registered platforms
@@ -44,6 +186,63 @@ impl ConverterRegistry {
         None
     }
 
+    /// Convert a known frontend link for a specific platform back to its canonical URL
+    pub fn canonicalize(&self, url: &str, platform: &str) -> Option<String> {
+        self.converters
+            .iter()
+            .filter(|c| c.platform_name() == platform)
+            .find_map(|c| c.canonicalize(url))
+    }
+
+    /// Rewrite a link on any known frontend of the platform to the given converter and options.
+    /// Returns None if the link is not a frontend link or already uses the target frontend, and
+    /// never moves frontend links back to the platform itself (e.g. fxbsky.app to bsky.app).
+    pub fn retarget(&self, url: &str, platform: &str, converter: &str, options: &ConverterOptions) -> Option<String> {
+        if self.is_passthrough(platform, converter) {
+            return None;
+        }
+
+        let already_converted = self
+            .converters
+            .iter()
+            .filter(|c| c.platform_name() == platform)
+            .any(|c| c.is_converted(url, converter));
+        if already_converted {
+            return None;
+        }
+
+        let canonical = self.canonicalize(url, platform)?;
+        let converted = self
+            .converters
+            .iter()
+            .filter(|c| c.platform_name() == platform)
+            .find_map(|c| c.convert_with_options(&canonical, converter, options))?;
+
+        // A mode of the target frontend (d.fxtwitter.com) is kept unless the options pick another one
+        let (host, converted_host) = (link_host(url), link_host(&converted));
+        if host == converted_host || host.ends_with(&format!(".{}", converted_host)) {
+            return None;
+        }
+
+        Some(converted)
+    }
+
+    /// Try to canonicalize a URL by checking all registered platforms
+    pub fn canonicalize_any(&self, url: &str) -> Option<(String, String)> {
+        self.converters.iter().find_map(|c| {
+            c.canonicalize(url)
+                .map(|canonical| (c.platform_name().to_string(), canonical))
+        })
+    }
+
+    /// Checks if a URL belongs to the platform, either as a vanilla link or on a known frontend
+    pub fn recognizes(&self, url: &str, platform: &str) -> bool {
+        self.converters
+            .iter()
+            .filter(|c| c.platform_name() == platform)
+            .any(|c| c.matches(url) || c.canonicalize(url).is_some())
+    }
+
     /// Get a converter by platform name
     pub fn get_converter(&self, platform: &str) -> Option<Arc<dyn LinkConverterStrategy>> {
         self.converters
@@ -52,22 +251,70 @@ impl ConverterRegistry {
             .cloned()
     }
 
+    /// The fediverse converter, whose instances are configured at runtime
+    pub fn fediverse(&self) -> &FediverseConverter {
+        &self.fediverse
+    }
+
+    /// The AMP and mobile link normaliser, which runs ahead of every platform and whose rules can be
+    /// switched off at runtime
+    pub fn normalizer(&self) -> &LinkNormalizer {
+        &self.normalizer
+    }
+
+    /// The privacy frontends, whose instances are configured at runtime
+    pub fn privacy_frontends(&self) -> &[Arc<PrivacyFrontendConverter>] {
+        &self.privacy
+    }
+
+    /// Replace the self-hosted frontends of a platform, skipping entries named like any other converter
+    /// of the platform: built-in ones, privacy frontends and user definitions. Returns None for platforms
+    /// without custom frontends, otherwise the entries that were skipped.
+    pub fn configure_custom_frontends(&self, platform: &str, frontends: &[CustomFrontend]) -> Option<Vec<String>> {
+        let custom = self.custom.iter().find(|c| c.platform_name() == platform)?;
+        let reserved: Vec<String> = self
+            .converters
+            .iter()
+            .filter(|c| c.platform_name() == platform)
+            .filter(|c| Arc::as_ptr(c) as *const () != Arc::as_ptr(custom) as *const ())
+            .flat_map(|c| c.available_converters())
+            .collect();
+
+        Some(custom.configure(frontends, &reserved))
+    }
+
     /// Get all registered platform names
     pub fn platforms(&self) -> Vec<&str> {
-        self.converters
-            .iter()
-            .map(|c| c.platform_name())
-            .collect()
+        let mut platforms: Vec<&str> = Vec::new();
+        for converter in &self.converters {
+            if !platforms.contains(&converter.platform_name()) {
+                platforms.push(converter.platform_name());
+            }
+        }
+        platforms
     }
 
     /// Get available converters for a specific platform
-    pub fn available_converters(&self, platform: &str) -> Vec<&'static str> {
-        self.get_converter(platform)
-            .map(|c| c.available_converters())
-            .unwrap_or_default()
+    pub fn available_converters(&self, platform: &str) -> Vec<String> {
+        let mut names: Vec<String> = Vec::new();
+        for converter in self.converters.iter().filter(|c| c.platform_name() == platform) {
+            for name in converter.available_converters() {
+                if !names.contains(&name) {
+                    names.push(name);
+                }
+            }
+        }
+        names
     }
 }
 
+// Host of a link without scheme or www. prefix, e.g. "fxtwitter.com"
+fn link_host(url: &str) -> &str {
+    let without_scheme = url.split_once("://").map_or(url, |(_, rest)| rest);
+    let without_www = without_scheme.strip_prefix("www.").unwrap_or(without_scheme);
+    without_www.split(['/', '?', '#']).next().unwrap_or(without_www)
+}
+
 impl Default for ConverterRegistry {
     fn default() -> Self {
         Self::new()
@@ -87,6 +334,12 @@ mod tests {
         assert!(platforms.contains(&"bluesky"));
         assert!(platforms.contains(&"tiktok"));
         assert!(platforms.contains(&"instagram"));
+        assert!(platforms.contains(&"reddit"));
+        assert!(platforms.contains(&"threads"));
+        assert!(platforms.contains(&"pixiv"));
+        assert!(platforms.contains(&"youtube"));
+        assert!(platforms.contains(&"music"));
+        assert!(platforms.contains(&"fediverse"));
     }
 
     #[test]
@@ -141,6 +394,32 @@ mod tests {
         assert_eq!(result, Some("https://ddinstagram.com/user/reel/ABC123".to_string()));
     }
 
+    #[test]
+    fn test_registry_convert_reddit() {
+        let registry = ConverterRegistry::new();
+        
+        let result = registry.convert(
+            "https://old.reddit.com/r/rust/comments/abc123/some_title/",
+            "reddit",
+            "rxddit"
+        );
+        
+        assert_eq!(result, Some("https://rxddit.com/r/rust/comments/abc123/some_title".to_string()));
+    }
+
+    #[test]
+    fn test_registry_convert_threads() {
+        let registry = ConverterRegistry::new();
+        
+        let result = registry.convert(
+            "https://www.threads.net/@zuck/post/C8AbCdEfGhI",
+            "threads",
+            "fixthreads"
+        );
+        
+        assert_eq!(result, Some("https://fixthreads.net/@zuck/post/C8AbCdEfGhI".to_string()));
+    }
+
     #[test]
     fn test_registry_try_convert_any() {
         let registry = ConverterRegistry::new();
@@ -166,17 +445,146 @@ mod tests {
         assert_eq!(converted, "https://fxbsky.app/profile/user.bsky.social/post/123456");
     }
 
+    #[test]
+    fn test_registry_canonicalize() {
+        let registry = ConverterRegistry::new();
+
+        let result = registry.canonicalize("https://vxtwitter.com/user/status/123456", "twitter");
+        assert_eq!(result, Some("https://x.com/user/status/123456".to_string()));
+
+        // Wrong platform
+        assert!(registry.canonicalize("https://vxtwitter.com/user/status/123456", "bluesky").is_none());
+
+        let result = registry.canonicalize_any("https://ddinstagram.com/user/reel/ABC123");
+        assert_eq!(
+            result,
+            Some(("instagram".to_string(), "https://www.instagram.com/user/reel/ABC123".to_string()))
+        );
+
+        assert!(registry.canonicalize_any("https://x.com/user/status/123456").is_none());
+    }
+
+    #[test]
+    fn test_registry_retarget() {
+        let registry = ConverterRegistry::new();
+        let options = ConverterOptions::default();
+
+        let result = registry.retarget("https://vxtwitter.com/user/status/123456", "twitter", "fixupx", &options);
+        assert_eq!(result, Some("https://fixupx.com/user/status/123456".to_string()));
+
+        let result = registry.retarget("https://kkinstagram.com/user/reel/ABC123/", "instagram", "ddinstagram", &options);
+        assert_eq!(result, Some("https://ddinstagram.com/user/reel/ABC123".to_string()));
+
+        // Already on the selected frontend
+        assert!(registry.retarget("https://www.fixupx.com/user/status/123456", "twitter", "fixupx", &options).is_none());
+        // Vanilla links are handled by convert, not retarget
+        assert!(registry.retarget("https://x.com/user/status/123456", "twitter", "fixupx", &options).is_none());
+    }
+
+    #[test]
+    fn test_registry_recognizes() {
+        let registry = ConverterRegistry::new();
+
+        assert!(registry.recognizes("https://x.com/user/status/1", "twitter"));
+        assert!(registry.recognizes("https://fxtwitter.com/user/status/1", "twitter"));
+        assert!(!registry.recognizes("https://x.com/user/status/1", "bluesky"));
+        assert!(!registry.recognizes("https://example.com/", "twitter"));
+    }
+
     #[test]
     fn test_registry_available_converters() {
         let registry = ConverterRegistry::new();
         
         let twitter_converters = registry.available_converters("twitter");
-        assert_eq!(twitter_converters, vec!["fxtwitter", "vxtwitter", "fixupx", "fixvx"]);
+        assert_eq!(twitter_converters, vec!["fxtwitter", "vxtwitter", "fixupx", "fixvx", "nitter"]);
         
         let bluesky_converters = registry.available_converters("bluesky");
         assert_eq!(bluesky_converters, vec!["bsky", "fxbsky", "vxbsky", "bskye", "bskyx"]);
     }
 
+    #[test]
+    fn test_registry_with_definitions() {
+        let definitions = vec![
+            ConverterDefinition {
+                platform: "twitter".to_string(),
+                name: "vxtwitter".to_string(),
+                pattern: r"^(?:https?://)?(?:www\.)?(?:twitter|x)\.com/(?P<user>[^/]+)/status/(?P<id>[0-9]+)".to_string(),
+                captures: vec![],
+                template: "https://c.vxtwitter.com/{user}/status/{id}".to_string(),
+            },
+            ConverterDefinition {
+                platform: "twitter".to_string(),
+                name: "myembed".to_string(),
+                pattern: r"^(?:https?://)?(?:www\.)?(?:twitter|x)\.com/(?P<user>[^/]+)/status/(?P<id>[0-9]+)".to_string(),
+                captures: vec![],
+                template: "https://embed.example.com/{id}".to_string(),
+            },
+        ];
+        let (registry, errors) = ConverterRegistry::with_definitions(&definitions);
+        assert!(errors.is_empty());
+
+        // Links matched by another definition are not mistaken for ones this converter leaves alone
+        assert_eq!(
+            registry.try_convert("https://x.com/user/status/123", "twitter", "myembed", &Default::default()).map(|c| c.url),
+            Ok("https://embed.example.com/123".to_string())
+        );
+
+        // User definitions override built-in converters with the same name
+        let result = registry.convert("https://x.com/user/status/123", "twitter", "vxtwitter");
+        assert_eq!(result, Some("https://c.vxtwitter.com/user/status/123".to_string()));
+
+        let result = registry.convert("https://x.com/user/status/123", "twitter", "myembed");
+        assert_eq!(result, Some("https://embed.example.com/123".to_string()));
+
+        // Definitions report the ids found by the built-in converter of their platform
+        let conversion = registry.try_convert("https://x.com/user/status/123", "twitter", "myembed", &Default::default()).unwrap();
+        assert_eq!(conversion.ids.get("status").map(String::as_str), Some("123"));
+
+        // Built-in converters without an override keep working
+        let result = registry.convert("https://x.com/user/status/123", "twitter", "fxtwitter");
+        assert_eq!(result, Some("https://fxtwitter.com/user/status/123".to_string()));
+
+        assert_eq!(
+            registry.available_converters("twitter"),
+            vec!["vxtwitter", "myembed", "fxtwitter", "fixupx", "fixvx", "nitter"]
+        );
+        assert_eq!(registry.platforms().iter().filter(|p| **p == "twitter").count(), 1);
+
+        // Definitions for platforms without settings could never be selected
+        let mastodon = ConverterDefinition { platform: "Mastodon".to_string(), ..definitions[1].clone() };
+        let (registry, errors) = ConverterRegistry::with_definitions(&[mastodon]);
+        assert_eq!(errors, vec!["Converter 'myembed': unknown platform 'Mastodon'".to_string()]);
+        assert!(!registry.platforms().contains(&"mastodon"));
+    }
+
+    #[test]
+    fn test_registry_try_convert() {
+        let registry = ConverterRegistry::new();
+        let options = ConverterOptions::default();
+
+        let conversion = registry.try_convert("https://www.tiktok.com/@user/video/123", "tiktok", "tfxktok", &options).unwrap();
+        assert_eq!(conversion.url, "https://tfxktok.com/@user/video/123");
+        assert_eq!(conversion.ids.get("user").map(String::as_str), Some("user"));
+        assert_eq!(conversion.ids.get("post").map(String::as_str), Some("123"));
+
+        assert_eq!(
+            registry.try_convert("https://www.tiktok.com/@user/video/123", "tiktok", "fxtwitter", &options),
+            Err(ConversionError::UnknownConverter { platform: "tiktok".to_string(), converter: "fxtwitter".to_string() })
+        );
+        assert_eq!(
+            registry.try_convert("https://tiktokez.com/@user/video/123", "tiktok", "tfxktok", &options),
+            Err(ConversionError::AlreadyConverted { platform: "tiktok".to_string(), converter: "tfxktok".to_string() })
+        );
+        assert_eq!(
+            registry.try_convert("https://www.youtube.com/watch?v=dQw4w9WgXcQ", "youtube", "watch", &options),
+            Err(ConversionError::IdentityConversion { platform: "youtube".to_string(), converter: "watch".to_string() })
+        );
+        assert_eq!(
+            registry.try_convert("https://example.com/post/123", "unknown", "converter", &options),
+            Err(ConversionError::NotSupported)
+        );
+    }
+
     #[test]
     fn test_registry_unknown_platform() {
         let registry = ConverterRegistry::new();
@@ -0,0 +1,134 @@
+use super::conversion::extracted_ids;
+use super::{domain_alternation, LinkConverterStrategy};
+use regex::Regex;
+use std::collections::BTreeMap;
+
+/// Converter names and the frontend domains they map to
+const FRONTENDS: &[(&str, &str)] = &[
+    ("fixthreads", "fixthreads.net"),
+    ("vxthreads", "vxthreads.net"),
+];
+
+/// Post path shared by vanilla and frontend links: `/@<user>/post/<code>`
+const POST_PATH: &str = r"/@([^/?#\s]+)/post/([A-Za-z0-9_-]+)";
+
+pub struct ThreadsConverter {
+    vanilla_regex: Regex,
+    frontend_regex: Regex,
+}
+
+impl ThreadsConverter {
+    pub fn new() -> Self {
+        Self {
+            // Threads is served from both threads.net and threads.com
+            vanilla_regex: Regex::new(&format!(
+                r"^(?:https?://)?(?:www\.)?threads\.(?:net|com){}",
+                POST_PATH
+            )).unwrap(),
+            // Matches links on any known frontend
+            frontend_regex: Regex::new(&format!(
+                r"^(?:https?://)?(?:www\.|[a-z]\.)?(?:{}){}",
+                domain_alternation(FRONTENDS.iter().map(|(_, domain)| *domain)),
+                POST_PATH
+            )).unwrap(),
+        }
+    }
+}
+
+impl LinkConverterStrategy for ThreadsConverter {
+    fn platform_name(&self) -> &str {
+        "threads"
+    }
+
+    fn matches(&self, url: &str) -> bool {
+        self.vanilla_regex.is_match(url)
+    }
+
+    fn convert(&self, url: &str, converter: &str) -> Option<String> {
+        if !self.matches(url) {
+            return None;
+        }
+
+        let caps = self.vanilla_regex.captures(url)?;
+        let username = caps.get(1)?.as_str();
+        let post_code = caps.get(2)?.as_str();
+
+        let converter = converter.to_lowercase();
+        let (_, converter_domain) = FRONTENDS.iter().find(|(name, _)| *name == converter)?;
+
+        Some(format!(
+            "https://{}/@{}/post/{}",
+            converter_domain, username, post_code
+        ))
+    }
+
+    fn extract_ids(&self, url: &str) -> BTreeMap<String, String> {
+        let Some(caps) = self.vanilla_regex.captures(url) else {
+            return BTreeMap::new();
+        };
+        extracted_ids([
+            ("user", caps.get(1).map(|m| m.as_str())),
+            ("post", caps.get(2).map(|m| m.as_str())),
+        ])
+    }
+
+    fn available_converters(&self) -> Vec<String> {
+        FRONTENDS.iter().map(|(name, _)| name.to_string()).collect()
+    }
+
+    fn canonicalize(&self, url: &str) -> Option<String> {
+        let caps = self.frontend_regex.captures(url)?;
+        let username = caps.get(1)?.as_str();
+        let post_code = caps.get(2)?.as_str();
+
+        Some(format!("https://www.threads.com/@{}/post/{}", username, post_code))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_threads_matches() {
+        let converter = ThreadsConverter::new();
+
+        assert!(converter.matches("https://www.threads.net/@zuck/post/C8AbCdEfGhI"));
+        assert!(converter.matches("https://threads.com/@zuck/post/C8AbCdEfGhI?xmt=abc"));
+        assert!(converter.matches("http://www.threads.com/@some.user/post/C8AbC-d_E"));
+
+        // Should not match already-converted URLs or profiles
+        assert!(!converter.matches("https://fixthreads.net/@zuck/post/C8AbCdEfGhI"));
+        assert!(!converter.matches("https://vxthreads.net/@zuck/post/C8AbCdEfGhI"));
+        assert!(!converter.matches("https://www.threads.net/@zuck"));
+    }
+
+    #[test]
+    fn test_different_converter_outputs() {
+        let converter = ThreadsConverter::new();
+        let vanilla_url = "https://www.threads.net/@zuck/post/C8AbCdEfGhI?xmt=abc";
+
+        let expected = vec![
+            ("fixthreads", "https://fixthreads.net/@zuck/post/C8AbCdEfGhI"),
+            ("vxthreads", "https://vxthreads.net/@zuck/post/C8AbCdEfGhI"),
+        ];
+
+        for (converter_type, expected_url) in expected {
+            let result = converter.convert(vanilla_url, converter_type);
+            assert_eq!(result, Some(expected_url.to_string()));
+        }
+
+        assert!(converter.convert("https://fixthreads.net/@zuck/post/C8AbCdEfGhI", "vxthreads").is_none());
+    }
+
+    #[test]
+    fn test_threads_canonicalize() {
+        let converter = ThreadsConverter::new();
+
+        assert_eq!(
+            converter.canonicalize("https://fixthreads.net/@zuck/post/C8AbCdEfGhI"),
+            Some("https://www.threads.com/@zuck/post/C8AbCdEfGhI".to_string())
+        );
+        assert!(converter.canonicalize("https://www.threads.net/@zuck/post/C8AbCdEfGhI").is_none());
+    }
+}
@@ -1,27 +1,74 @@
-use super::LinkConverterStrategy;
+use super::conversion::extracted_ids;
+use super::{domain_alternation, LinkConverterStrategy};
 use regex::Regex;
+use std::collections::BTreeMap;
+
+/// Converter names and the frontend domains they map to
+const FRONTENDS: &[(&str, &str)] = &[
+    ("tfxktok", "tfxktok.com"),
+    ("tiktokez", "tiktokez.com"),
+];
+
+/// Post path shared by vanilla and frontend links: `/@<user>/video/<id>` or `/@<user>/photo/<id>`
+const POST_PATH: &str = r"/@([^/?#\s]+)/(video|photo)/([0-9]+)";
+
+/// Short link path from the mobile share sheet: `/<code>` on vm./vt. or `/t/<code>`
+const SHORT_PATH: &str = r"/([A-Za-z0-9]+)(?:[/?#]|$)";
 
 pub struct TikTokConverter {
     vanilla_regex: Regex,
+    short_regex: Regex,
+    frontend_regex: Regex,
+    frontend_short_regex: Regex,
 }
 
 impl TikTokConverter {
     pub fn new() -> Self {
+        let frontend_domains = domain_alternation(FRONTENDS.iter().map(|(_, domain)| *domain));
+
         Self {
-            vanilla_regex: Regex::new(
-                r"^(?:https?://)?(?:www\.)?(tiktok\.com)/@([^/]+)/video/([0-9]+)"
-            ).unwrap(),
+            vanilla_regex: Regex::new(&format!(
+                r"^(?:https?://)?(?:www\.|m\.)?tiktok\.com{}",
+                POST_PATH
+            )).unwrap(),
+            // Short links: vm.tiktok.com/<code>, vt.tiktok.com/<code> and tiktok.com/t/<code>
+            short_regex: Regex::new(&format!(
+                r"^(?:https?://)?(?:(?:vm|vt)\.tiktok\.com|(?:www\.|m\.)?tiktok\.com/t){}",
+                SHORT_PATH
+            )).unwrap(),
+            // Matches links on any known frontend
+            frontend_regex: Regex::new(&format!(
+                r"^(?:https?://)?(?:www\.|[a-z]\.)?(?:{}){}",
+                frontend_domains, POST_PATH
+            )).unwrap(),
+            frontend_short_regex: Regex::new(&format!(
+                r"^(?:https?://)?(?:(?:vm|vt)\.(?:{0})|(?:www\.|[a-z]\.)?(?:{0})/t){1}",
+                frontend_domains, SHORT_PATH
+            )).unwrap(),
         }
     }
+
+    // Path understood by both tiktok.com and its frontends; short codes always use the /t/ form
+    fn extract_path(post_regex: &Regex, short_regex: &Regex, url: &str) -> Option<String> {
+        if let Some(caps) = post_regex.captures(url) {
+            let username = caps.get(1)?.as_str();
+            let kind = caps.get(2)?.as_str();
+            let post_id = caps.get(3)?.as_str();
+            return Some(format!("/@{}/{}/{}", username, kind, post_id));
+        }
+
+        let caps = short_regex.captures(url)?;
+        Some(format!("/t/{}", caps.get(1)?.as_str()))
+    }
 }
 
 impl LinkConverterStrategy for TikTokConverter {
-    fn platform_name(&self) -> &'static str {
+    fn platform_name(&self) -> &str {
         "tiktok"
     }
 
     fn matches(&self, url: &str) -> bool {
-        self.vanilla_regex.is_match(url)
+        self.vanilla_regex.is_match(url) || self.short_regex.is_match(url)
     }
 
     fn convert(&self, url: &str, converter: &str) -> Option<String> {
@@ -29,24 +76,34 @@ impl LinkConverterStrategy for TikTokConverter {
             return None;
         }
 
-        let caps = self.vanilla_regex.captures(url)?;
-        let username = caps.get(2)?.as_str();
-        let video_id = caps.get(3)?.as_str();
+        let path = Self::extract_path(&self.vanilla_regex, &self.short_regex, url)?;
 
-        let converter_domain = match converter.to_lowercase().as_str() {
-            "tfxktok" => "tfxktok.com",
-            "tiktokez" => "tiktokez.com",
-            _ => return None,
-        };
+        let converter = converter.to_lowercase();
+        let (_, converter_domain) = FRONTENDS.iter().find(|(name, _)| *name == converter)?;
 
-        Some(format!(
-            "https://{}/@{}/video/{}",
-            converter_domain, username, video_id
-        ))
+        Some(format!("https://{}{}", converter_domain, path))
     }
 
-    fn available_converters(&self) -> Vec<&'static str> {
-        vec!["tfxktok", "tiktokez"]
+    fn extract_ids(&self, url: &str) -> BTreeMap<String, String> {
+        if let Some(caps) = self.vanilla_regex.captures(url) {
+            return extracted_ids([
+                ("user", caps.get(1).map(|m| m.as_str())),
+                ("post", caps.get(3).map(|m| m.as_str())),
+            ]);
+        }
+
+        let code = self.short_regex.captures(url).and_then(|caps| caps.get(1));
+        extracted_ids([("short_code", code.map(|m| m.as_str()))])
+    }
+
+    fn available_converters(&self) -> Vec<String> {
+        FRONTENDS.iter().map(|(name, _)| name.to_string()).collect()
+    }
+
+    fn canonicalize(&self, url: &str) -> Option<String> {
+        let path = Self::extract_path(&self.frontend_regex, &self.frontend_short_regex, url)?;
+
+        Some(format!("https://www.tiktok.com{}", path))
     }
 }
 
@@ -62,7 +119,15 @@ mod tests {
         assert!(converter.matches("https://tiktok.com/@user/video/123456"));
         assert!(converter.matches("http://www.tiktok.com/@user/video/123456"));
         
+        assert!(converter.matches("https://m.tiktok.com/@user/video/123456"));
+        assert!(converter.matches("https://www.tiktok.com/@user/photo/123456"));
+        assert!(converter.matches("https://vm.tiktok.com/ZMabc123/"));
+        assert!(converter.matches("https://vt.tiktok.com/ZSabc123/"));
+        assert!(converter.matches("https://www.tiktok.com/t/ZTabc123/"));
+
         assert!(!converter.matches("https://tfxktok.com/@user/video/123456"));
+        assert!(!converter.matches("https://vm.tfxktok.com/ZMabc123/"));
+        assert!(!converter.matches("https://www.tiktok.com/@user"));
     }
 
     #[test]
@@ -93,4 +158,45 @@ mod tests {
             assert_eq!(result, Some(expected_url.to_string()));
         }
     }
+
+    #[test]
+    fn test_tiktok_canonicalize() {
+        let converter = TikTokConverter::new();
+
+        assert_eq!(
+            converter.canonicalize("https://tfxktok.com/@user/video/123456"),
+            Some("https://www.tiktok.com/@user/video/123456".to_string())
+        );
+        assert_eq!(
+            converter.canonicalize("https://www.tiktokez.com/@user/video/123456"),
+            Some("https://www.tiktok.com/@user/video/123456".to_string())
+        );
+        assert!(converter.canonicalize("https://www.tiktok.com/@user/video/123456").is_none());
+    }
+
+    #[test]
+    fn test_short_links_and_photos() {
+        let converter = TikTokConverter::new();
+
+        let cases = vec![
+            ("https://vm.tiktok.com/ZMabc123/", "https://tfxktok.com/t/ZMabc123"),
+            ("https://vt.tiktok.com/ZSabc123", "https://tfxktok.com/t/ZSabc123"),
+            ("https://www.tiktok.com/t/ZTabc123/?_r=1", "https://tfxktok.com/t/ZTabc123"),
+            ("https://m.tiktok.com/@user/video/123456", "https://tfxktok.com/@user/video/123456"),
+            ("https://www.tiktok.com/@user/photo/123456?is_from_webapp=1", "https://tfxktok.com/@user/photo/123456"),
+        ];
+
+        for (url, expected) in cases {
+            assert_eq!(converter.convert(url, "tfxktok"), Some(expected.to_string()), "for {}", url);
+        }
+
+        assert_eq!(
+            converter.canonicalize("https://vm.tfxktok.com/ZMabc123/"),
+            Some("https://www.tiktok.com/t/ZMabc123".to_string())
+        );
+        assert_eq!(
+            converter.canonicalize("https://tiktokez.com/@user/photo/123456"),
+            Some("https://www.tiktok.com/@user/photo/123456".to_string())
+        );
+    }
 }
@@ -1,23 +1,73 @@
-use super::LinkConverterStrategy;
+use super::conversion::extracted_ids;
+use super::options::{frontend_host, supported_options, ConverterOption, ConverterOptions};
+use super::{domain_alternation, LinkConverterStrategy};
 use regex::Regex;
+use std::collections::BTreeMap;
+
+/// Converter names and the frontend domains they map to
+const FRONTENDS: &[(&str, &str)] = &[
+    ("fxtwitter", "fxtwitter.com"),
+    ("vxtwitter", "vxtwitter.com"),
+    ("fixupx", "fixupx.com"),
+    ("fixvx", "fixvx.com"),
+];
+
+/// Modes each frontend exposes through subdomains and path suffixes
+const OPTIONS: &[(&str, &[ConverterOption])] = &[
+    ("fxtwitter", &[ConverterOption::DirectMedia, ConverterOption::Gallery, ConverterOption::TextOnly, ConverterOption::Translate]),
+    ("fixupx", &[ConverterOption::DirectMedia, ConverterOption::Gallery, ConverterOption::TextOnly, ConverterOption::Translate]),
+    ("vxtwitter", &[ConverterOption::DirectMedia]),
+    ("fixvx", &[ConverterOption::DirectMedia]),
+];
+
+/// Status path shared by vanilla and frontend links: `/<user>/status/<id>`, `/i/status/<id>` or
+/// `/i/web/status/<id>`, optionally followed by a media suffix (`/photo/2`) and a language code (`/en`)
+const STATUS_PATH: &str = r"/(?:i(?:/web)?|([^/\s?#]+))/status/([0-9]+)(/(?:photo|video)/[1-9])?(?:/([a-z]{2}(?:-[a-z]{2,4})?)(?:[/?#]|$))?";
 
 pub struct TwitterConverter {
     vanilla_regex: Regex,
+    frontend_regex: Regex,
 }
 
 impl TwitterConverter {
     pub fn new() -> Self {
         Self {
             // Only matches vanilla twitter.com and x.com domains
-            vanilla_regex: Regex::new(
-                r"^(?:https?://)?(?:www\.)?(twitter\.com|x\.com)/([^/]+)/status/([0-9]+)"
-            ).unwrap(),
+            vanilla_regex: Regex::new(&format!(
+                r"^(?:https?://)?(?:www\.|mobile\.)?(twitter\.com|x\.com){}",
+                STATUS_PATH
+            )).unwrap(),
+            // Matches links on any known frontend, including mode subdomains like d. or g.
+            frontend_regex: Regex::new(&format!(
+                r"^(?:https?://)?(?:www\.|[a-z]\.)?({}){}",
+                domain_alternation(FRONTENDS.iter().map(|(_, domain)| *domain)),
+                STATUS_PATH
+            )).unwrap(),
         }
     }
+
+    // Rebuilds the status path, keeping the media suffix and language code
+    fn status_path(caps: &regex::Captures) -> Option<String> {
+        let status_id = caps.get(3)?.as_str();
+
+        let mut path = match caps.get(2) {
+            Some(username) => format!("/{}/status/{}", username.as_str(), status_id),
+            None => format!("/i/status/{}", status_id),
+        };
+        if let Some(media) = caps.get(4) {
+            path.push_str(media.as_str());
+        }
+        if let Some(language) = caps.get(5) {
+            path.push('/');
+            path.push_str(language.as_str());
+        }
+
+        Some(path)
+    }
 }
 
 impl LinkConverterStrategy for TwitterConverter {
-    fn platform_name(&self) -> &'static str {
+    fn platform_name(&self) -> &str {
         "twitter"
     }
 
@@ -26,30 +76,53 @@ impl LinkConverterStrategy for TwitterConverter {
     }
 
     fn convert(&self, url: &str, converter: &str) -> Option<String> {
+        self.convert_with_options(url, converter, &ConverterOptions::default())
+    }
+
+    fn supported_options(&self, converter: &str) -> Vec<ConverterOption> {
+        supported_options(OPTIONS, converter)
+    }
+
+    fn convert_with_options(&self, url: &str, converter: &str, options: &ConverterOptions) -> Option<String> {
         if !self.matches(url) {
             return None;
         }
 
         let caps = self.vanilla_regex.captures(url)?;
-        let username = caps.get(2)?.as_str();
-        let status_id = caps.get(3)?.as_str();
+        let mut path = Self::status_path(&caps)?;
+
+        let converter = converter.to_lowercase();
+        let (_, converter_domain) = FRONTENDS.iter().find(|(name, _)| *name == converter)?;
 
-        let converter_domain = match converter.to_lowercase().as_str() {
-            "fxtwitter" => "fxtwitter.com",
-            "vxtwitter" => "vxtwitter.com",
-            "fixupx" => "fixupx.com",
-            "fixvx" => "fixvx.com",
-            _ => return None,
+        // A language already in the link takes precedence over the translation option
+        let supported = self.supported_options(&converter);
+        if let Some(language) = options.language(&supported).filter(|_| caps.get(5).is_none()) {
+            path.push('/');
+            path.push_str(language);
+        }
+
+        Some(format!("https://{}{}", frontend_host(converter_domain, options, &supported), path))
+    }
+
+    fn extract_ids(&self, url: &str) -> BTreeMap<String, String> {
+        let Some(caps) = self.vanilla_regex.captures(url) else {
+            return BTreeMap::new();
         };
+        extracted_ids([
+            ("user", caps.get(2).map(|m| m.as_str())),
+            ("status", caps.get(3).map(|m| m.as_str())),
+        ])
+    }
 
-        Some(format!(
-            "https://{}/{}/status/{}",
-            converter_domain, username, status_id
-        ))
+    fn available_converters(&self) -> Vec<String> {
+        FRONTENDS.iter().map(|(name, _)| name.to_string()).collect()
     }
 
-    fn available_converters(&self) -> Vec<&'static str> {
-        vec!["fxtwitter", "vxtwitter", "fixupx", "fixvx"]
+    fn canonicalize(&self, url: &str) -> Option<String> {
+        let caps = self.frontend_regex.captures(url)?;
+        let path = Self::status_path(&caps)?;
+
+        Some(format!("https://x.com{}", path))
     }
 }
 
@@ -121,4 +194,108 @@ mod tests {
             assert!(result.ends_with(&format!("/{}/status/{}", expected_user, expected_id)));
         }
     }
+
+    #[test]
+    fn test_twitter_canonicalize() {
+        let converter = TwitterConverter::new();
+
+        let cases = vec![
+            "https://fxtwitter.com/user/status/123456",
+            "https://vxtwitter.com/user/status/123456",
+            "http://www.fixupx.com/user/status/123456",
+            "https://d.fxtwitter.com/user/status/123456",
+            "fixvx.com/user/status/123456",
+        ];
+
+        for url in cases {
+            assert_eq!(
+                converter.canonicalize(url),
+                Some("https://x.com/user/status/123456".to_string()),
+                "Frontend URL '{}' should canonicalize",
+                url
+            );
+        }
+
+        // Vanilla and unknown links are not frontend links
+        assert!(converter.canonicalize("https://x.com/user/status/123456").is_none());
+        assert!(converter.canonicalize("https://example.com/user/status/123456").is_none());
+    }
+
+    #[test]
+    fn test_preserve_suffixes() {
+        let converter = TwitterConverter::new();
+
+        let cases = vec![
+            ("https://x.com/user/status/123/photo/2", "https://fxtwitter.com/user/status/123/photo/2"),
+            ("https://x.com/user/status/123/video/1?s=20", "https://fxtwitter.com/user/status/123/video/1"),
+            ("https://x.com/user/status/123/en", "https://fxtwitter.com/user/status/123/en"),
+            ("https://x.com/user/status/123/photo/1/pt-br", "https://fxtwitter.com/user/status/123/photo/1/pt-br"),
+            // Not language codes
+            ("https://x.com/user/status/123/analytics", "https://fxtwitter.com/user/status/123"),
+            ("https://x.com/user/status/123/likes", "https://fxtwitter.com/user/status/123"),
+        ];
+
+        for (url, expected) in cases {
+            assert_eq!(converter.convert(url, "fxtwitter"), Some(expected.to_string()), "for {}", url);
+        }
+    }
+
+    #[test]
+    fn test_mobile_and_i_status_forms() {
+        let converter = TwitterConverter::new();
+
+        assert_eq!(
+            converter.convert("https://mobile.twitter.com/user/status/123", "vxtwitter"),
+            Some("https://vxtwitter.com/user/status/123".to_string())
+        );
+        assert_eq!(
+            converter.convert("https://x.com/i/status/123", "vxtwitter"),
+            Some("https://vxtwitter.com/i/status/123".to_string())
+        );
+        assert_eq!(
+            converter.convert("https://twitter.com/i/web/status/123/photo/1", "vxtwitter"),
+            Some("https://vxtwitter.com/i/status/123/photo/1".to_string())
+        );
+        // Usernames starting with "i" are still usernames
+        assert_eq!(
+            converter.convert("https://x.com/ibm/status/123", "vxtwitter"),
+            Some("https://vxtwitter.com/ibm/status/123".to_string())
+        );
+        assert_eq!(
+            converter.canonicalize("https://fxtwitter.com/i/status/123/en"),
+            Some("https://x.com/i/status/123/en".to_string())
+        );
+    }
+
+    #[test]
+    fn test_converter_options() {
+        let converter = TwitterConverter::new();
+        let options = ConverterOptions {
+            gallery: true,
+            translate: Some("en".to_string()),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            converter.convert_with_options("https://x.com/user/status/123", "fxtwitter", &options),
+            Some("https://g.fxtwitter.com/user/status/123/en".to_string())
+        );
+        // A language in the original link is kept
+        assert_eq!(
+            converter.convert_with_options("https://x.com/user/status/123/ja", "fxtwitter", &options),
+            Some("https://g.fxtwitter.com/user/status/123/ja".to_string())
+        );
+        // vxtwitter has no gallery mode or translation
+        assert_eq!(
+            converter.convert_with_options("https://x.com/user/status/123", "vxtwitter", &options),
+            Some("https://vxtwitter.com/user/status/123".to_string())
+        );
+        assert_eq!(converter.supported_options("fixvx"), vec![ConverterOption::DirectMedia]);
+
+        // Links with a mode subdomain are still recognised as frontend links
+        assert_eq!(
+            converter.canonicalize("https://g.fxtwitter.com/user/status/123/en"),
+            Some("https://x.com/user/status/123/en".to_string())
+        );
+    }
 }
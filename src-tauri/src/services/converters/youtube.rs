@@ -0,0 +1,144 @@
+use super::conversion::extracted_ids;
+use super::LinkConverterStrategy;
+use crate::services::query_params::param_value;
+use regex::Regex;
+use std::collections::BTreeMap;
+
+pub struct YouTubeConverter {
+    video_regex: Regex,
+    watch_regex: Regex,
+    short_link_regex: Regex,
+}
+
+impl YouTubeConverter {
+    pub fn new() -> Self {
+        Self {
+            // Watch pages, Shorts and live streams on youtube.com and its mobile subdomain.
+            // music.youtube.com belongs to the music platform.
+            video_regex: Regex::new(
+                r"^(?:https?://)?(?:(?:www|m)\.)?youtube\.com/(?:watch\?(?:[^#\s]*&)?v=|shorts/|live/)([A-Za-z0-9_-]{11})(?:[/?&#]|$)"
+            ).unwrap(),
+            // Desktop watch pages, the output of the "watch" converter
+            watch_regex: Regex::new(
+                r"^(?:https?://)?(?:www\.)?youtube\.com/watch\?"
+            ).unwrap(),
+            // Short links: youtu.be/<id>
+            short_link_regex: Regex::new(
+                r"^(?:https?://)?(?:www\.)?youtu\.be/([A-Za-z0-9_-]{11})(?:[/?#]|$)"
+            ).unwrap(),
+        }
+    }
+
+    fn video_id<'a>(&self, url: &'a str) -> Option<&'a str> {
+        self.video_regex
+            .captures(url)
+            .or_else(|| self.short_link_regex.captures(url))
+            .and_then(|caps| caps.get(1))
+            .map(|m| m.as_str())
+    }
+}
+
+// Only keep timestamps that look like "90", "90s" or "1m30s"
+fn timestamp(url: &str) -> Option<&str> {
+    param_value(url, "t").filter(|t| !t.is_empty() && t.chars().all(|c| c.is_ascii_alphanumeric()))
+}
+
+impl LinkConverterStrategy for YouTubeConverter {
+    fn platform_name(&self) -> &str {
+        "youtube"
+    }
+
+    fn matches(&self, url: &str) -> bool {
+        self.video_id(url).is_some()
+    }
+
+    fn convert(&self, url: &str, converter: &str) -> Option<String> {
+        let video_id = self.video_id(url)?;
+        let timestamp = timestamp(url);
+
+        match converter.to_lowercase().as_str() {
+            // Full watch URL, which also takes Shorts out of the vertical player.
+            // Links already in that form only need their tracking parameters cleaned.
+            "watch" if self.watch_regex.is_match(url) => None,
+            "watch" => Some(match timestamp {
+                Some(t) => format!("https://www.youtube.com/watch?v={}&t={}", video_id, t),
+                None => format!("https://www.youtube.com/watch?v={}", video_id),
+            }),
+            "short" if self.short_link_regex.is_match(url) => None,
+            "short" => Some(match timestamp {
+                Some(t) => format!("https://youtu.be/{}?t={}", video_id, t),
+                None => format!("https://youtu.be/{}", video_id),
+            }),
+            _ => None,
+        }
+    }
+
+    fn content_params(&self) -> &[&str] {
+        &["v", "t"]
+    }
+
+    fn extract_ids(&self, url: &str) -> BTreeMap<String, String> {
+        extracted_ids([("video", self.video_id(url)), ("timestamp", timestamp(url))])
+    }
+
+    fn available_converters(&self) -> Vec<String> {
+        vec!["watch".to_string(), "short".to_string()]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_youtube_matches() {
+        let converter = YouTubeConverter::new();
+
+        assert!(converter.matches("https://www.youtube.com/watch?v=dQw4w9WgXcQ"));
+        assert!(converter.matches("https://m.youtube.com/watch?feature=share&v=dQw4w9WgXcQ"));
+        assert!(converter.matches("https://youtube.com/shorts/dQw4w9WgXcQ?si=abc"));
+        assert!(converter.matches("https://www.youtube.com/live/dQw4w9WgXcQ"));
+        assert!(converter.matches("https://youtu.be/dQw4w9WgXcQ?si=abc&t=42"));
+
+        assert!(!converter.matches("https://www.youtube.com/@channel"));
+        assert!(!converter.matches("https://www.youtube.com/watch?v=short"));
+        assert!(!converter.matches("https://notyoutu.be/dQw4w9WgXcQ"));
+        assert!(!converter.matches("https://music.youtube.com/watch?v=dQw4w9WgXcQ"));
+    }
+
+    #[test]
+    fn test_shorts_to_watch() {
+        let converter = YouTubeConverter::new();
+
+        let cases = vec![
+            ("https://youtube.com/shorts/dQw4w9WgXcQ?si=abc", "https://www.youtube.com/watch?v=dQw4w9WgXcQ"),
+            ("https://youtu.be/dQw4w9WgXcQ?si=abc&t=42", "https://www.youtube.com/watch?v=dQw4w9WgXcQ&t=42"),
+            ("https://www.youtube.com/live/dQw4w9WgXcQ?t=1m30s", "https://www.youtube.com/watch?v=dQw4w9WgXcQ&t=1m30s"),
+        ];
+
+        for (url, expected) in cases {
+            assert_eq!(converter.convert(url, "watch"), Some(expected.to_string()), "for {}", url);
+        }
+
+        assert_eq!(
+            converter.convert("https://m.youtube.com/watch?v=dQw4w9WgXcQ", "watch"),
+            Some("https://www.youtube.com/watch?v=dQw4w9WgXcQ".to_string())
+        );
+        assert!(converter.convert("https://www.youtube.com/watch?v=dQw4w9WgXcQ", "watch").is_none());
+    }
+
+    #[test]
+    fn test_watch_to_short_link() {
+        let converter = YouTubeConverter::new();
+
+        assert_eq!(
+            converter.convert("https://www.youtube.com/watch?v=dQw4w9WgXcQ&t=42&si=abc", "short"),
+            Some("https://youtu.be/dQw4w9WgXcQ?t=42".to_string())
+        );
+        assert_eq!(
+            converter.convert("https://youtube.com/shorts/dQw4w9WgXcQ", "short"),
+            Some("https://youtu.be/dQw4w9WgXcQ".to_string())
+        );
+        assert!(converter.convert("https://youtu.be/dQw4w9WgXcQ", "short").is_none());
+    }
+}
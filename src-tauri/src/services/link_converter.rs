@@ -1,14 +1,47 @@
+use crate::services::clear_urls::ClearUrlsRules;
+use crate::services::converters::conversion::{Conversion, ConversionError};
+use crate::services::converters::declarative::ConverterDefinitions;
+use crate::services::converters::options::ConverterOptions;
 use crate::services::converters::registry::ConverterRegistry;
+use crate::services::link_scanner::LinkScanner;
+use crate::services::redirect_unwrapper::RedirectUnwrapper;
+use std::path::Path;
 
 /// Facade for the converter registry to maintain backward compatibility
 pub struct LinkConverter {
     registry: ConverterRegistry,
+    scanner: LinkScanner,
+    unwrapper: RedirectUnwrapper,
+    clear_urls: ClearUrlsRules,
 }
 
 impl LinkConverter {
     pub fn new() -> Self {
         LinkConverter {
             registry: ConverterRegistry::new(),
+            scanner: LinkScanner::new(),
+            unwrapper: RedirectUnwrapper::new(),
+            clear_urls: ClearUrlsRules::default(),
+        }
+    }
+
+    /// Create a converter that also uses the user definitions stored at the given path
+    pub fn with_definitions_file(path: &Path) -> Self {
+        let definitions = ConverterDefinitions::from_file_or_default(path).unwrap_or_else(|e| {
+            eprintln!("Failed to load converter definitions: {}", e);
+            ConverterDefinitions::default()
+        });
+
+        let (registry, errors) = ConverterRegistry::with_definitions(&definitions.converters);
+        for error in errors {
+            eprintln!("Skipping converter definition: {}", error);
+        }
+
+        LinkConverter {
+            registry,
+            scanner: LinkScanner::new(),
+            unwrapper: RedirectUnwrapper::new(),
+            clear_urls: ClearUrlsRules::default(),
         }
     }
 
@@ -17,10 +50,52 @@ impl LinkConverter {
         self.registry.convert(url, platform, converter)
     }
 
+    /// Convert a link using a specific platform, converter and options, explaining why it was left alone
+    /// when it is not converted
+    pub fn try_convert_link(
+        &self,
+        url: &str,
+        platform: &str,
+        converter: &str,
+        options: &ConverterOptions,
+    ) -> Result<Conversion, ConversionError> {
+        self.registry.try_convert(url, platform, converter, options)
+    }
+
+    /// Convert a frontend link for a specific platform back to its canonical URL
+    pub fn canonicalize_link(&self, url: &str, platform: &str) -> Option<String> {
+        self.registry.canonicalize(url, platform)
+    }
+
+    /// Rewrite a link on another frontend of the platform to the given converter and options
+    pub fn retarget_link(&self, url: &str, platform: &str, converter: &str, options: &ConverterOptions) -> Option<String> {
+        self.registry.retarget(url, platform, converter, options)
+    }
+
     /// Get the underlying registry for advanced usage
     pub fn registry(&self) -> &ConverterRegistry {
         &self.registry
     }
+
+    /// Get the scanner used to find links inside free-form text
+    pub fn scanner(&self) -> &LinkScanner {
+        &self.scanner
+    }
+
+    /// Get the unwrapper that recovers links hidden behind redirect services
+    pub fn unwrapper(&self) -> &RedirectUnwrapper {
+        &self.unwrapper
+    }
+
+    /// Get the generic ClearURLs cleaning rules
+    pub fn clear_urls(&self) -> &ClearUrlsRules {
+        &self.clear_urls
+    }
+
+    /// Replace the ClearURLs rules, e.g. with ones loaded from the user's data.min.json
+    pub fn set_clear_urls(&mut self, rules: ClearUrlsRules) {
+        self.clear_urls = rules;
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,173 @@
+use regex::Regex;
+use std::ops::Range;
+
+/// Finds links embedded in free-form text so they can be rewritten in place
+pub struct LinkScanner {
+    url_regex: Regex,
+    bare_url_regex: Regex,
+}
+
+impl LinkScanner {
+    pub fn new() -> Self {
+        Self {
+            // Anything that starts with a scheme and runs until whitespace or a delimiter.
+            // at:// is included for Bluesky AT-URIs.
+            url_regex: Regex::new(r#"(?i)\b(?:https?|at)://[^\s<>"'`]+"#).unwrap(),
+            // A host and path without a scheme (e.g. x.com/user/status/1) that starts a word
+            bare_url_regex: Regex::new(r#"(?i)(?:^|[\s(\[{<"'])((?:[a-z0-9-]+\.)+[a-z]{2,}/[^\s<>"'`]*)"#).unwrap(),
+        }
+    }
+
+    /// Returns the byte ranges of every link found in the text, in order.
+    /// Links without a scheme are included too; callers decide whether they belong to a known host.
+    pub fn find_links(&self, text: &str) -> Vec<Range<usize>> {
+        let with_scheme = self.url_regex.find_iter(text);
+        let bare = self.bare_url_regex.captures_iter(text).filter_map(|caps| caps.get(1));
+
+        let mut links: Vec<Range<usize>> = with_scheme
+            .chain(bare)
+            .map(|m| m.start()..m.start() + trimmed_link_len(m.as_str()))
+            .collect();
+        links.sort_by_key(|range| range.start);
+        links
+    }
+
+    /// Replaces every link for which `convert` returns a new value.
+    /// Returns None if no link in the text was replaced.
+    pub fn replace_links<F>(&self, text: &str, mut convert: F) -> Option<String>
+    where
+        F: FnMut(&str) -> Option<String>,
+    {
+        let mut result = String::with_capacity(text.len());
+        let mut last_end = 0;
+        let mut replaced = false;
+
+        for range in self.find_links(text) {
+            if let Some(converted) = convert(&text[range.clone()]) {
+                result.push_str(&text[last_end..range.start]);
+                result.push_str(&converted);
+                last_end = range.end;
+                replaced = true;
+            }
+        }
+
+        if !replaced {
+            return None;
+        }
+
+        result.push_str(&text[last_end..]);
+        Some(result)
+    }
+}
+
+impl Default for LinkScanner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// Drops sentence punctuation and unbalanced closing brackets from the end of a link
+fn trimmed_link_len(link: &str) -> usize {
+    let mut end = link.len();
+
+    while let Some(last) = link[..end].chars().last() {
+        let candidate = &link[..end];
+        let trim = match last {
+            '.' | ',' | ';' | ':' | '!' | '?' => true,
+            ')' => candidate.matches('(').count() < candidate.matches(')').count(),
+            ']' => candidate.matches('[').count() < candidate.matches(']').count(),
+            '}' => candidate.matches('{').count() < candidate.matches('}').count(),
+            _ => false,
+        };
+
+        if !trim {
+            break;
+        }
+        end -= last.len_utf8();
+    }
+
+    end
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn links<'a>(scanner: &LinkScanner, text: &'a str) -> Vec<&'a str> {
+        scanner.find_links(text).into_iter().map(|r| &text[r]).collect()
+    }
+
+    #[test]
+    fn test_find_links_in_text() {
+        let scanner = LinkScanner::new();
+        let text = "look at https://x.com/user/status/1 and http://bsky.app/profile/a/post/b!";
+
+        assert_eq!(
+            links(&scanner, text),
+            vec!["https://x.com/user/status/1", "http://bsky.app/profile/a/post/b"]
+        );
+    }
+
+    #[test]
+    fn test_find_at_uris() {
+        let scanner = LinkScanner::new();
+        let text = "post: at://did:plc:abc/app.bsky.feed.post/3kabc, thanks";
+
+        assert_eq!(links(&scanner, text), vec!["at://did:plc:abc/app.bsky.feed.post/3kabc"]);
+    }
+
+    #[test]
+    fn test_trailing_punctuation_and_brackets() {
+        let scanner = LinkScanner::new();
+
+        assert_eq!(links(&scanner, "(https://x.com/user/status/1)."), vec!["https://x.com/user/status/1"]);
+        assert_eq!(links(&scanner, "<https://x.com/user/status/1>"), vec!["https://x.com/user/status/1"]);
+        assert_eq!(
+            links(&scanner, "see https://en.wikipedia.org/wiki/Rust_(language), ok"),
+            vec!["https://en.wikipedia.org/wiki/Rust_(language)"]
+        );
+    }
+
+    #[test]
+    fn test_no_links() {
+        let scanner = LinkScanner::new();
+
+        assert!(scanner.find_links("nothing to see here").is_empty());
+        assert!(scanner.find_links("e.g. 1.5/2 or user@x.com/user").is_empty());
+    }
+
+    #[test]
+    fn test_find_links_without_scheme() {
+        let scanner = LinkScanner::new();
+        let text = "x.com/user/status/1, (bsky.app/profile/a) and https://www.tiktok.com/@a/video/1 vm.tiktok.com/ZM1/.";
+
+        assert_eq!(
+            links(&scanner, text),
+            vec!["x.com/user/status/1", "bsky.app/profile/a", "https://www.tiktok.com/@a/video/1", "vm.tiktok.com/ZM1/"]
+        );
+    }
+
+    #[test]
+    fn test_replace_links_keeps_surrounding_text() {
+        let scanner = LinkScanner::new();
+        let text = "  first: https://a.com/1\nsecond (https://b.com/2) 🌍 https://c.com/3.\n";
+
+        let result = scanner.replace_links(text, |link| match link {
+            "https://a.com/1" => Some("https://fixed-a.com/1".to_string()),
+            "https://b.com/2" => Some("https://fixed-b.com/2".to_string()),
+            _ => None,
+        });
+
+        assert_eq!(
+            result,
+            Some("  first: https://fixed-a.com/1\nsecond (https://fixed-b.com/2) 🌍 https://c.com/3.\n".to_string())
+        );
+    }
+
+    #[test]
+    fn test_replace_links_none_converted() {
+        let scanner = LinkScanner::new();
+
+        assert!(scanner.replace_links("hello https://example.com/", |_| None).is_none());
+    }
+}
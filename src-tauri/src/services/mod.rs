@@ -1,3 +1,7 @@
+pub mod clear_urls;
 pub mod clipboard;
 pub mod link_converter;
+pub mod link_scanner;
+pub mod query_params;
+pub mod redirect_unwrapper;
 pub mod converters;
\ No newline at end of file
@@ -0,0 +1,189 @@
+/// Splits a URL into the part before the query, the query string and the fragment
+pub fn split_url(url: &str) -> (&str, Option<&str>, Option<&str>) {
+    let (rest, fragment) = match url.split_once('#') {
+        Some((rest, fragment)) => (rest, Some(fragment)),
+        None => (url, None),
+    };
+
+    match rest.split_once('?') {
+        Some((base, query)) => (base, Some(query), fragment),
+        None => (rest, None, fragment),
+    }
+}
+
+/// Returns the query string of a URL, if it has a non-empty one
+pub fn query_string(url: &str) -> Option<&str> {
+    split_url(url).1.filter(|query| !query.is_empty())
+}
+
+/// Name of a query parameter, e.g. "s" for "s=20"
+pub fn param_name(param: &str) -> &str {
+    param.split_once('=').map_or(param, |(name, _)| name)
+}
+
+/// Value of the first query parameter with the given name, e.g. "20" for "s" in "?s=20"
+pub fn param_value<'a>(url: &'a str, name: &str) -> Option<&'a str> {
+    query_string(url)?
+        .split('&')
+        .find(|param| param_name(param) == name)
+        .map(|param| param.split_once('=').map_or("", |(_, value)| value))
+}
+
+/// Checks a parameter name against a pattern; a trailing `*` matches any suffix (e.g. "utm_*")
+pub fn param_matches(name: &str, pattern: &str) -> bool {
+    match pattern.strip_suffix('*') {
+        Some(prefix) => name.starts_with(prefix),
+        None => name == pattern,
+    }
+}
+
+/// Keeps only the query parameters for which `keep` returns true, preserving order and fragment
+pub fn filter_query<F>(url: &str, keep: F) -> String
+where
+    F: Fn(&str) -> bool,
+{
+    let (base, query, fragment) = split_url(url);
+
+    let Some(query) = query else {
+        return url.to_string();
+    };
+
+    let kept: Vec<&str> = query
+        .split('&')
+        .filter(|param| !param.is_empty() && keep(param_name(param)))
+        .collect();
+
+    let mut result = base.to_string();
+    if !kept.is_empty() {
+        result.push('?');
+        result.push_str(&kept.join("&"));
+    }
+    if let Some(fragment) = fragment {
+        result.push('#');
+        result.push_str(fragment);
+    }
+    result
+}
+
+/// Appends query parameters to a URL after the ones it already has, keeping any fragment at the end.
+/// Parameters the URL already has keep their value.
+pub fn append_query(url: &str, query: Option<&str>) -> String {
+    let Some(query) = query.filter(|query| !query.is_empty()) else {
+        return url.to_string();
+    };
+    let (base, existing, fragment) = split_url(url);
+
+    let mut params: Vec<&str> = existing.unwrap_or_default().split('&').filter(|p| !p.is_empty()).collect();
+    for param in query.split('&').filter(|p| !p.is_empty()) {
+        if !params.iter().any(|p| param_name(p) == param_name(param)) {
+            params.push(param);
+        }
+    }
+
+    let mut result = format!("{}?{}", base, params.join("&"));
+    if let Some(fragment) = fragment {
+        result.push('#');
+        result.push_str(fragment);
+    }
+    result
+}
+
+/// Percent-encodes a value for use inside a query parameter
+pub fn encode_component(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => encoded.push(byte as char),
+            _ => encoded.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    encoded
+}
+
+/// Decodes a percent-encoded query parameter value. Returns None if the result is not valid UTF-8.
+pub fn decode_component(value: &str) -> Option<String> {
+    let bytes = value.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let hex = bytes.get(i + 1..i + 3).and_then(|h| std::str::from_utf8(h).ok());
+        match (bytes[i], hex.and_then(|h| u8::from_str_radix(h, 16).ok())) {
+            (b'%', Some(byte)) => {
+                decoded.push(byte);
+                i += 3;
+            }
+            (b'+', _) => {
+                decoded.push(b' ');
+                i += 1;
+            }
+            (byte, _) => {
+                decoded.push(byte);
+                i += 1;
+            }
+        }
+    }
+
+    String::from_utf8(decoded).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_url() {
+        assert_eq!(split_url("https://a.com/p?x=1#top"), ("https://a.com/p", Some("x=1"), Some("top")));
+        assert_eq!(split_url("https://a.com/p#top?x"), ("https://a.com/p", None, Some("top?x")));
+        assert_eq!(split_url("https://a.com/p"), ("https://a.com/p", None, None));
+        assert_eq!(query_string("https://a.com/p?"), None);
+    }
+
+    #[test]
+    fn test_param_value() {
+        let url = "https://a.com/watch?v=abc&t=90&flag#t=5";
+
+        assert_eq!(param_value(url, "v"), Some("abc"));
+        assert_eq!(param_value(url, "t"), Some("90"));
+        assert_eq!(param_value(url, "flag"), Some(""));
+        assert_eq!(param_value(url, "x"), None);
+    }
+
+    #[test]
+    fn test_param_matches() {
+        assert!(param_matches("utm_source", "utm_*"));
+        assert!(param_matches("s", "s"));
+        assert!(!param_matches("si", "s"));
+        assert!(!param_matches("xutm_source", "utm_*"));
+    }
+
+    #[test]
+    fn test_filter_query() {
+        let url = "https://x.com/user/status/1?s=20&t=abc&lang=en#frag";
+
+        assert_eq!(
+            filter_query(url, |name| name != "s" && name != "t"),
+            "https://x.com/user/status/1?lang=en#frag"
+        );
+        assert_eq!(filter_query(url, |_| false), "https://x.com/user/status/1#frag");
+        assert_eq!(filter_query("https://x.com/user", |_| false), "https://x.com/user");
+    }
+
+    #[test]
+    fn test_append_query() {
+        assert_eq!(append_query("https://a.com/p", Some("x=1")), "https://a.com/p?x=1");
+        assert_eq!(append_query("https://a.com/p?v=2", Some("x=1&v=3")), "https://a.com/p?v=2&x=1");
+        assert_eq!(append_query("https://a.com/p", None), "https://a.com/p");
+        assert_eq!(append_query("https://a.com/p#top", Some("x=1")), "https://a.com/p?x=1#top");
+    }
+
+    #[test]
+    fn test_encode_and_decode_component() {
+        let url = "https://mastodon.social/@user/123?a=1&b=2";
+        let encoded = encode_component(url);
+
+        assert_eq!(encoded, "https%3A%2F%2Fmastodon.social%2F%40user%2F123%3Fa%3D1%26b%3D2");
+        assert_eq!(decode_component(&encoded), Some(url.to_string()));
+        assert_eq!(decode_component("100%"), Some("100%".to_string()));
+    }
+}
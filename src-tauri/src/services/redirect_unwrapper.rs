@@ -0,0 +1,145 @@
+use crate::services::query_params::{decode_component, param_value};
+use regex::Regex;
+
+/// Wrappers nested deeper than this are left as they are
+const MAX_DEPTH: usize = 5;
+
+/// A redirect service and the query parameters that may hold the wrapped link
+struct RedirectWrapper {
+    regex: Regex,
+    params: &'static [&'static str],
+}
+
+/// A short link service whose target follows from the path alone
+struct ShortLink {
+    regex: Regex,
+    target: &'static str,
+}
+
+/// Recovers the original link from redirect wrappers added by search engines, social networks and mail clients
+pub struct RedirectUnwrapper {
+    wrappers: Vec<RedirectWrapper>,
+    short_links: Vec<ShortLink>,
+}
+
+impl RedirectUnwrapper {
+    pub fn new() -> Self {
+        let wrapper = |pattern: &str, params: &'static [&'static str]| RedirectWrapper {
+            regex: Regex::new(pattern).unwrap(),
+            params,
+        };
+
+        Self {
+            wrappers: vec![
+                // google.com/url?q=... from search results and Gmail
+                wrapper(r"^(?:https?://)?(?:www\.)?google\.[a-z.]+/url\?", &["q", "url"]),
+                // l.facebook.com/l.php?u=... and the Messenger/Instagram equivalents
+                wrapper(r"^(?:https?://)?(?:l|lm)\.facebook\.com/l\.php\?", &["u"]),
+                wrapper(r"^(?:https?://)?(?:l\.instagram\.com|l\.messenger\.com/l\.php)/?\?", &["u"]),
+                // Outlook SafeLinks: <region>.safelinks.protection.outlook.com/?url=...
+                wrapper(r"^(?:https?://)?[a-z0-9-]+\.safelinks\.protection\.outlook\.com/?\?", &["url"]),
+                // Slack's outbound link redirect
+                wrapper(r"^(?:https?://)?slack-redir\.net/link\?", &["url"]),
+                // Links in YouTube descriptions
+                wrapper(r"^(?:https?://)?(?:www\.)?youtube\.com/redirect\?", &["q"]),
+            ],
+            short_links: vec![
+                // Bluesky starter pack short links, which bsky.app resolves itself
+                ShortLink {
+                    regex: Regex::new(r"^(?:https?://)?go\.bsky\.app/([A-Za-z0-9_-]+)/?(?:[?#]\S*)?$").unwrap(),
+                    target: "https://bsky.app/starter-pack-short/$1",
+                },
+            ],
+        }
+    }
+
+    /// Returns the innermost wrapped link, or None if the URL is not a known redirect wrapper
+    pub fn unwrap(&self, url: &str) -> Option<String> {
+        let mut current = self.unwrap_once(url)?;
+
+        for _ in 1..MAX_DEPTH {
+            match self.unwrap_once(&current) {
+                Some(inner) => current = inner,
+                None => break,
+            }
+        }
+
+        Some(current)
+    }
+
+    fn unwrap_once(&self, url: &str) -> Option<String> {
+        if let Some(short_link) = self.short_links.iter().find(|s| s.regex.is_match(url)) {
+            return Some(short_link.regex.replace(url, short_link.target).into_owned());
+        }
+
+        let wrapper = self.wrappers.iter().find(|w| w.regex.is_match(url))?;
+
+        wrapper
+            .params
+            .iter()
+            .filter_map(|param| param_value(url, param))
+            .filter_map(decode_component)
+            .find(|target| target.starts_with("https://") || target.starts_with("http://"))
+    }
+}
+
+impl Default for RedirectUnwrapper {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unwrap_known_wrappers() {
+        let unwrapper = RedirectUnwrapper::new();
+        let target = "https://x.com/user/status/123";
+
+        for url in [
+            "https://www.google.com/url?sa=t&q=https%3A%2F%2Fx.com%2Fuser%2Fstatus%2F123&ved=abc",
+            "https://www.google.co.uk/url?url=https://x.com/user/status/123",
+            "https://l.facebook.com/l.php?u=https%3A%2F%2Fx.com%2Fuser%2Fstatus%2F123&h=AT0",
+            "https://eur01.safelinks.protection.outlook.com/?url=https%3A%2F%2Fx.com%2Fuser%2Fstatus%2F123&data=05",
+            "https://slack-redir.net/link?url=https%3A%2F%2Fx.com%2Fuser%2Fstatus%2F123",
+            "https://www.youtube.com/redirect?event=video_description&q=https%3A%2F%2Fx.com%2Fuser%2Fstatus%2F123",
+        ] {
+            assert_eq!(unwrapper.unwrap(url), Some(target.to_string()), "for {}", url);
+        }
+    }
+
+    #[test]
+    fn test_unwrap_nested_wrappers() {
+        let unwrapper = RedirectUnwrapper::new();
+        let url = "https://eur01.safelinks.protection.outlook.com/?url=https%3A%2F%2Fwww.google.com%2Furl%3Fq%3Dhttps%253A%252F%252Fx.com%252Fuser%252Fstatus%252F123";
+
+        assert_eq!(unwrapper.unwrap(url), Some("https://x.com/user/status/123".to_string()));
+    }
+
+    #[test]
+    fn test_unwrap_short_links() {
+        let unwrapper = RedirectUnwrapper::new();
+
+        assert_eq!(
+            unwrapper.unwrap("https://go.bsky.app/AbC123"),
+            Some("https://bsky.app/starter-pack-short/AbC123".to_string())
+        );
+        assert_eq!(
+            unwrapper.unwrap("go.bsky.app/AbC123?utm_source=share"),
+            Some("https://bsky.app/starter-pack-short/AbC123".to_string())
+        );
+        assert!(unwrapper.unwrap("https://go.bsky.app/AbC123/more").is_none());
+    }
+
+    #[test]
+    fn test_not_a_wrapper() {
+        let unwrapper = RedirectUnwrapper::new();
+
+        assert!(unwrapper.unwrap("https://x.com/user/status/123").is_none());
+        assert!(unwrapper.unwrap("https://www.google.com/search?q=rust").is_none());
+        // Only web links are unwrapped
+        assert!(unwrapper.unwrap("https://www.google.com/url?q=javascript%3Aalert(1)").is_none());
+    }
+}
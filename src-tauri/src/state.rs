@@ -1,9 +1,13 @@
 use crate::config::app_config::SourcesConfig;
+use crate::config::frontend::ConfigWarning;
 use std::sync::{Arc, Mutex};
 use std::thread;
 use std::time::Duration;
 use tauri::{AppHandle, Emitter};
 
+/// Problems found while validating the configuration, at startup and after custom converters change
+pub struct ConfigWarnings(pub Mutex<Vec<ConfigWarning>>);
+
 pub struct StateManager {
     state: Arc<Mutex<SourcesConfig>>,
     config_path: String,
@@ -1,6 +1,6 @@
 use crate::{
     handlers::window_visibility_manager,
-    tray_menu::menu_component::{build_menu_items, process_menu_event},
+    tray_menu::menu_component::{build_converter_menu, build_menu_items, process_menu_event},
 };
 use tauri::{
     menu::Menu,
@@ -8,18 +8,40 @@ use tauri::{
     AppHandle, Runtime,
 };
 
+pub const TRAY_ID: &str = "main";
+
 pub fn create_menu<R: Runtime>(app: &AppHandle<R>) -> tauri::Result<Menu<R>> {
     let menu_items = build_menu_items(app)?;
+    let converter_menu = build_converter_menu(app)?;
 
-    let item_refs: Vec<&dyn tauri::menu::IsMenuItem<R>> = menu_items
+    let mut item_refs: Vec<&dyn tauri::menu::IsMenuItem<R>> = menu_items
         .iter()
         .map(|item| item as &dyn tauri::menu::IsMenuItem<R>)
         .collect();
+    if let Some(converter_menu) = &converter_menu {
+        item_refs.insert(0, converter_menu);
+    }
 
     let menu = Menu::with_items(app, &item_refs)?;
     Ok(menu)
 }
 
+/// Rebuild the tray menu so it reflects the current config, e.g. converters marked unavailable
+pub fn refresh_tray_menu<R: Runtime>(app: &AppHandle<R>) {
+    let Some(tray) = app.tray_by_id(TRAY_ID) else {
+        return;
+    };
+
+    match create_menu(app) {
+        Ok(menu) => {
+            if let Err(e) = tray.set_menu(Some(menu)) {
+                eprintln!("Failed to update tray menu: {}", e);
+            }
+        }
+        Err(e) => eprintln!("Failed to rebuild tray menu: {}", e),
+    }
+}
+
 fn handle_tray_icon_click_event<R: Runtime>(tray: &TrayIcon<R>, event: tauri::tray::TrayIconEvent) {
     match event {
         TrayIconEvent::Click {
@@ -38,7 +60,7 @@ fn handle_tray_icon_click_event<R: Runtime>(tray: &TrayIcon<R>, event: tauri::tr
 }
 
 pub fn create_tray<R: Runtime>(app: &AppHandle<R>, menu: &Menu<R>) -> tauri::Result<TrayIcon<R>> {
-    let tray = TrayIconBuilder::new()
+    let tray = TrayIconBuilder::with_id(TRAY_ID)
         .on_tray_icon_event(|tray, event| {
             handle_tray_icon_click_event(tray, event);
         })
@@ -1,4 +1,10 @@
-use tauri::{menu::MenuItem, AppHandle, Runtime};
+use crate::config::frontend::get_platform_metadata;
+use crate::state::StateManager;
+use crate::tray_menu::MenuId;
+use tauri::{
+    menu::{CheckMenuItem, MenuItem, Submenu},
+    AppHandle, Manager, Runtime,
+};
 
 // Define the menu structure
 pub const MENUS: [(&str, &str, bool, Option<&str>); 6] = [
@@ -21,6 +27,38 @@ pub fn build_menu_items<R: Runtime>(app: &AppHandle<R>) -> tauri::Result<Vec<Men
     Ok(items)
 }
 
+/// Submenu listing the preferred converters of every enabled platform, checked while available.
+/// Clicking one marks it unavailable or restores it. Missing until the config is loaded.
+pub fn build_converter_menu<R: Runtime>(app: &AppHandle<R>) -> tauri::Result<Option<Submenu<R>>> {
+    let Some(state_manager) = app.try_state::<StateManager>() else {
+        return Ok(None);
+    };
+    let state = state_manager.get_state();
+    let menu = Submenu::new(app, "Converters", true)?;
+
+    for source in state.sources.iter().filter(|source| source.get_operations().is_enabled()) {
+        let platform = source.get_platform_name();
+        let data = source.get_operations();
+        let unavailable = data.unavailable_converters();
+        let platform_menu = Submenu::new(app, get_platform_metadata(platform).title, true)?;
+
+        for converter in data.preferred_converters() {
+            let item = CheckMenuItem::with_id(
+                app,
+                MenuId::converter_id(platform, &converter),
+                &converter,
+                true,
+                !unavailable.contains(&converter),
+                None::<&str>,
+            )?;
+            platform_menu.append(&item)?;
+        }
+        menu.append(&platform_menu)?;
+    }
+
+    Ok(Some(menu))
+}
+
 fn create_menu_item<R: Runtime>(
     app: &AppHandle<R>,
     menu_id: &str,
@@ -1,6 +1,8 @@
 pub mod menu;
 pub mod menu_component;
 
+const CONVERTER_PREFIX: &str = "converter:";
+
 #[derive(Debug)]
 pub enum MenuId {
     Quit,
@@ -9,6 +11,8 @@ pub enum MenuId {
     Show,
     SetClipboard,
     GetClipboard,
+    /// Mark a preferred converter of a platform as unavailable, or restore it
+    ToggleConverter { platform: String, converter: String },
 }
 
 impl MenuId {
@@ -20,7 +24,19 @@ impl MenuId {
             "show" => Ok(MenuId::Show),
             "set_clipboard" => Ok(MenuId::SetClipboard),
             "get_clipboard" => Ok(MenuId::GetClipboard),
-            _ => Err(format!("Unknown menu id: {}", id)),
+            _ => id
+                .strip_prefix(CONVERTER_PREFIX)
+                .and_then(|rest| rest.rsplit_once(':'))
+                .map(|(platform, converter)| MenuId::ToggleConverter {
+                    platform: platform.to_string(),
+                    converter: converter.to_string(),
+                })
+                .ok_or_else(|| format!("Unknown menu id: {}", id)),
         }
     }
+
+    /// Id of the tray item toggling a converter, e.g. "converter:twitter:vxtwitter"
+    pub fn converter_id(platform: &str, converter: &str) -> String {
+        format!("{}{}:{}", CONVERTER_PREFIX, platform, converter)
+    }
 }
\ No newline at end of file
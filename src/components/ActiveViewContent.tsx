@@ -21,17 +21,53 @@ const howItWorksByPlatform: Record<string, string[]> = {
     "Paste your converted link into Discord, etc",
   ],
   tiktok: [
-    "Copy any TikTok video link (tiktok.com/@username/video/id)",
+    "Copy any TikTok video or photo link, including vm.tiktok.com short links",
     "The link will be automatically converted to your selected format",
     "Converted link replaces the original in your clipboard",
     "Paste your converted link into Discord, etc",
   ],
   instagram: [
-    "Copy any Instagram reel link (instagram.com/username/reel/id)",
+    "Copy any Instagram post, reel or IGTV link (instagram.com/p/id, /reel/id or /tv/id)",
     "The link will be automatically converted to your selected format",
     "Converted link replaces the original in your clipboard",
     "Paste your converted link into Discord, etc",
   ],
+  reddit: [
+    "Copy any Reddit post, comment, share or redd.it link",
+    "The link will be automatically converted to your selected format",
+    "Converted link replaces the original in your clipboard",
+    "Paste your converted link into Discord, etc",
+  ],
+  threads: [
+    "Copy any Threads post link (threads.net/@username/post/id)",
+    "The link will be automatically converted to your selected format",
+    "Converted link replaces the original in your clipboard",
+    "Paste your converted link into Discord, etc",
+  ],
+  pixiv: [
+    "Copy any Pixiv artwork link (pixiv.net/artworks/id)",
+    "Add #2, #3, ... to the link to embed a specific page of a multi-image work",
+    "The link will be automatically converted to your selected format",
+    "Paste your converted link into Discord, etc",
+  ],
+  youtube: [
+    "Copy any YouTube Shorts, watch or youtu.be link",
+    "Pick the full watch link or the youtu.be short link as your format",
+    "Timestamps are kept and share tracking is removed",
+    "Paste your converted link into Discord, etc",
+  ],
+  music: [
+    "Copy a track or album link from Spotify, Apple Music, Tidal, Deezer or YouTube Music",
+    "The link will be turned into a song.link or album.link page",
+    "Everyone can open it in the music service they use",
+    "Paste your converted link into Discord, etc",
+  ],
+  fediverse: [
+    "List the instances you read posts from in the settings above",
+    "Copy any post link from those instances (instance/@username/id)",
+    "The link will be converted to your embed frontend or opened on your home instance",
+    "Paste your converted link into Discord, etc",
+  ],
 };
 
 // Export ViewType as a type alias for string to maintain compatibility
@@ -113,9 +113,19 @@ export function AppSidebar({ activeView, setActiveView }: AppSidebarProps) {
                         <span>{source.metadata.title}</span>
                         <span
                           className={`ml-auto size-1 rounded-full ${
-                            source.data.enabled ? "bg-green-500" : "bg-red-500"
+                            source.data.passthrough
+                              ? "bg-amber-500"
+                              : source.data.enabled
+                                ? "bg-green-500"
+                                : "bg-red-500"
                           }`}
-                          title={source.data.enabled ? "Enabled" : "Disabled"}
+                          title={
+                            source.data.passthrough
+                              ? "Enabled, links are left unchanged"
+                              : source.data.enabled
+                                ? "Enabled"
+                                : "Disabled"
+                          }
                         />
                       </SidebarMenuButton>
                     </SidebarMenuItem>
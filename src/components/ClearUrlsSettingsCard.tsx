@@ -0,0 +1,48 @@
+import { invoke } from "@tauri-apps/api/core";
+import { ClearUrlsSettings } from "../types/config";
+import { Card, CardContent, CardHeader, CardTitle } from "./ui/card";
+import { Label } from "./ui/label";
+import { Switch } from "./ui/switch";
+
+interface ClearUrlsSettingsCardProps {
+  settings: ClearUrlsSettings;
+}
+
+const ClearUrlsSettingsCard = ({ settings }: ClearUrlsSettingsCardProps) => {
+  const setEnabled = async (enabled: boolean) => {
+    try {
+      await invoke("update_clear_urls_settings", {
+        settings: { enabled },
+      });
+    } catch (error) {
+      console.error("Failed to update ClearURLs settings:", error);
+    }
+  };
+
+  return (
+    <Card className="gap-2">
+      <CardHeader>
+        <CardTitle className="border-b-1 pb-4">Tracking Removal</CardTitle>
+      </CardHeader>
+      <CardContent className="space-y-4">
+        <div className="flex items-center justify-between space-x-2">
+          <Label htmlFor="switch-clear-urls">
+            Clean every copied link with ClearURLs rules
+          </Label>
+          <Switch
+            id="switch-clear-urls"
+            checked={settings.enabled}
+            onCheckedChange={setEnabled}
+          />
+        </div>
+        <p className="text-sm text-muted-foreground">
+          {settings.providers > 0
+            ? `${settings.providers} rule providers loaded from clearurls.json.`
+            : "No rules loaded. Place ClearURLs' data.min.json in the app data folder as clearurls.json and restart."}
+        </p>
+      </CardContent>
+    </Card>
+  );
+};
+
+export default ClearUrlsSettingsCard;
@@ -0,0 +1,77 @@
+import { invoke } from "@tauri-apps/api/core";
+import { ClipboardSettings } from "../types/config";
+import { Card, CardContent, CardHeader, CardTitle } from "./ui/card";
+import { Label } from "./ui/label";
+import { Switch } from "./ui/switch";
+
+interface ClipboardSettingsCardProps {
+  settings: ClipboardSettings;
+}
+
+const ClipboardSettingsCard = ({ settings }: ClipboardSettingsCardProps) => {
+  const updateSettings = async (changes: Partial<ClipboardSettings>) => {
+    try {
+      await invoke("update_clipboard_settings", {
+        settings: { ...settings, ...changes },
+      });
+    } catch (error) {
+      console.error("Failed to update clipboard settings:", error);
+    }
+  };
+
+  return (
+    <Card className="gap-2">
+      <CardHeader>
+        <CardTitle className="border-b-1 pb-4">Clipboard Settings</CardTitle>
+      </CardHeader>
+      <CardContent className="space-y-4">
+        <div className="flex items-center justify-between space-x-2">
+          <Label htmlFor="switch-scan-text">
+            Convert every link inside copied text
+          </Label>
+          <Switch
+            id="switch-scan-text"
+            checked={settings.scan_text}
+            onCheckedChange={(checked) => updateSettings({ scan_text: checked })}
+          />
+        </div>
+        <div className="flex items-center justify-between space-x-2">
+          <Label htmlFor="switch-retarget">
+            Move links from other embed services to your selected one
+          </Label>
+          <Switch
+            id="switch-retarget"
+            checked={settings.retarget}
+            onCheckedChange={(checked) => updateSettings({ retarget: checked })}
+          />
+        </div>
+        <div className="flex items-center justify-between space-x-2">
+          <Label htmlFor="switch-unwrap-redirects">
+            Remove redirect wrappers from search results and emails
+          </Label>
+          <Switch
+            id="switch-unwrap-redirects"
+            checked={settings.unwrap_redirects}
+            onCheckedChange={(checked) =>
+              updateSettings({ unwrap_redirects: checked })
+            }
+          />
+        </div>
+        <div className="flex items-center justify-between space-x-2">
+          <Label htmlFor="switch-canonicalize">
+            Turn embed links back into original links
+          </Label>
+          <Switch
+            id="switch-canonicalize"
+            checked={settings.canonicalize}
+            onCheckedChange={(checked) =>
+              updateSettings({ canonicalize: checked })
+            }
+          />
+        </div>
+      </CardContent>
+    </Card>
+  );
+};
+
+export default ClipboardSettingsCard;
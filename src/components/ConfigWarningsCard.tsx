@@ -0,0 +1,31 @@
+import { TriangleAlert } from "lucide-react";
+import { useConfigWarnings } from "../hooks/useConfigWarnings";
+import { Card, CardContent, CardHeader, CardTitle } from "./ui/card";
+
+const ConfigWarningsCard = () => {
+  const warnings = useConfigWarnings();
+
+  if (warnings.length === 0) {
+    return null;
+  }
+
+  return (
+    <Card className="gap-2 border-destructive">
+      <CardHeader>
+        <CardTitle className="flex gap-2 items-center">
+          <TriangleAlert size={24} className="text-destructive" />
+          Configuration Warnings
+        </CardTitle>
+      </CardHeader>
+      <CardContent>
+        <ul className="space-y-2 text-sm">
+          {warnings.map((warning, index) => (
+            <li key={`${warning.platform}-${index}`}>{warning.message}</li>
+          ))}
+        </ul>
+      </CardContent>
+    </Card>
+  );
+};
+
+export default ConfigWarningsCard;
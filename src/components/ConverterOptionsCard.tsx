@@ -0,0 +1,95 @@
+import { useEffect, useState } from "react";
+import { invoke } from "@tauri-apps/api/core";
+import { ConverterOption, ConverterOptions } from "../types/config";
+import { Card, CardContent, CardHeader, CardTitle } from "./ui/card";
+import { Input } from "./ui/input";
+import { Label } from "./ui/label";
+import { Switch } from "./ui/switch";
+
+interface ConverterOptionsCardProps {
+  platform: string;
+  converter: string;
+  options: ConverterOptions;
+  supported: ConverterOption[];
+}
+
+// Modes that take the frontend's subdomain; only one of them applies at a time
+const MODE_LABELS: [Exclude<ConverterOption, "translate">, string][] = [
+  ["direct_media", "Direct media links"],
+  ["gallery", "Gallery view"],
+  ["text_only", "Text only"],
+];
+
+const ConverterOptionsCard = ({
+  platform,
+  converter,
+  options,
+  supported,
+}: ConverterOptionsCardProps) => {
+  const [language, setLanguage] = useState(options.translate ?? "");
+
+  // Keep the input in sync with updates coming from the backend
+  useEffect(() => {
+    setLanguage(options.translate ?? "");
+  }, [options]);
+
+  const saveOptions = async (updated: ConverterOptions) => {
+    try {
+      await invoke("set_converter_options", { platform, options: updated });
+    } catch (error) {
+      console.error("Failed to update converter options:", error);
+    }
+  };
+
+  return (
+    <Card className="gap-2">
+      <CardHeader>
+        <CardTitle className="border-b-1 pb-4">Converter Options</CardTitle>
+      </CardHeader>
+      <CardContent className="space-y-4">
+        <p className="text-sm text-muted-foreground">
+          Modes offered by "{converter}". Direct media takes precedence over
+          the gallery view, and the gallery view over text only.
+        </p>
+        {MODE_LABELS.filter(([option]) => supported.includes(option)).map(
+          ([option, label]) => (
+            <div
+              key={option}
+              className="flex items-center justify-between space-x-2"
+            >
+              <Label htmlFor={`switch-${platform}-${option}`}>{label}</Label>
+              <Switch
+                id={`switch-${platform}-${option}`}
+                checked={options[option]}
+                onCheckedChange={(checked) =>
+                  saveOptions({ ...options, [option]: checked })
+                }
+              />
+            </div>
+          )
+        )}
+        {supported.includes("translate") && (
+          <div className="space-y-2">
+            <Label htmlFor={`input-${platform}-translate`}>
+              Translate posts into
+            </Label>
+            <Input
+              id={`input-${platform}-translate`}
+              placeholder="Language code, e.g. en"
+              value={language}
+              onChange={(event) => setLanguage(event.target.value)}
+              onBlur={() =>
+                saveOptions({
+                  ...options,
+                  translate: language.trim().toLowerCase() || null,
+                })
+              }
+            />
+          </div>
+        )}
+      </CardContent>
+    </Card>
+  );
+};
+
+export default ConverterOptionsCard;
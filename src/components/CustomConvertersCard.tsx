@@ -0,0 +1,118 @@
+import { useEffect, useState } from "react";
+import { invoke } from "@tauri-apps/api/core";
+import { toast } from "sonner";
+import { Trash2 } from "lucide-react";
+import { CustomFrontend } from "../types/config";
+import { Button } from "./ui/button";
+import { Card, CardContent, CardHeader, CardTitle } from "./ui/card";
+import { Input } from "./ui/input";
+
+interface CustomConvertersCardProps {
+  platform: string;
+  converters: CustomFrontend[];
+}
+
+const EMPTY_FRONTEND: CustomFrontend = { name: "", domain: "", template: "" };
+
+const CustomConvertersCard = ({
+  platform,
+  converters,
+}: CustomConvertersCardProps) => {
+  const [entries, setEntries] = useState<CustomFrontend[]>(converters);
+
+  // Keep the inputs in sync with updates coming from the backend
+  useEffect(() => {
+    setEntries(converters);
+  }, [converters]);
+
+  const saveConverters = async (updated: CustomFrontend[]) => {
+    // Rows still being filled in are kept locally until they have a name and domain
+    const complete = updated.filter(
+      (entry) => entry.name.trim() && entry.domain.trim()
+    );
+    try {
+      await invoke("update_custom_converters", {
+        platform,
+        converters: complete,
+      });
+    } catch (error) {
+      console.error("Failed to update custom converters:", error);
+      toast.error("Custom converters not saved", {
+        description: String(error),
+      });
+    }
+  };
+
+  const updateEntry = (index: number, changes: Partial<CustomFrontend>) => {
+    setEntries(
+      entries.map((entry, i) => (i === index ? { ...entry, ...changes } : entry))
+    );
+  };
+
+  const removeEntry = (index: number) => {
+    const updated = entries.filter((_, i) => i !== index);
+    setEntries(updated);
+    saveConverters(updated);
+  };
+
+  return (
+    <Card className="gap-2">
+      <CardHeader>
+        <CardTitle className="border-b-1 pb-4">Custom Converters</CardTitle>
+      </CardHeader>
+      <CardContent className="space-y-4">
+        <p className="text-sm text-muted-foreground">
+          Self-hosted frontends, selectable like the built-in converters. Use
+          ids of the original link between braces in the path, e.g.
+          "/{"{user}"}/status/{"{status}"}".
+        </p>
+        {entries.map((entry, index) => (
+          <div key={index} className="flex items-center space-x-2">
+            <Input
+              aria-label="Name"
+              placeholder="Name"
+              value={entry.name}
+              onChange={(event) => updateEntry(index, { name: event.target.value })}
+              onBlur={() => saveConverters(entries)}
+            />
+            <Input
+              aria-label="Domain"
+              placeholder="frontend.example.org"
+              value={entry.domain}
+              onChange={(event) =>
+                updateEntry(index, { domain: event.target.value })
+              }
+              onBlur={() => saveConverters(entries)}
+            />
+            <Input
+              aria-label="Path template"
+              placeholder="/{status}"
+              value={entry.template}
+              onChange={(event) =>
+                updateEntry(index, { template: event.target.value })
+              }
+              onBlur={() => saveConverters(entries)}
+            />
+            <Button
+              variant="ghost"
+              size="icon"
+              aria-label="Remove custom converter"
+              onClick={() => removeEntry(index)}
+            >
+              <Trash2 />
+            </Button>
+          </div>
+        ))}
+        <Button
+          variant="outline"
+          size="sm"
+          onClick={() => setEntries([...entries, EMPTY_FRONTEND])}
+        >
+          Add converter
+        </Button>
+      </CardContent>
+    </Card>
+  );
+};
+
+export default CustomConvertersCard;
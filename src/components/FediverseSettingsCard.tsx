@@ -0,0 +1,84 @@
+import { useEffect, useState } from "react";
+import { invoke } from "@tauri-apps/api/core";
+import { FediverseSettings } from "../types/config";
+import { Card, CardContent, CardHeader, CardTitle } from "./ui/card";
+import { Input } from "./ui/input";
+import { Label } from "./ui/label";
+
+interface FediverseSettingsCardProps {
+  settings: FediverseSettings;
+}
+
+const FediverseSettingsCard = ({ settings }: FediverseSettingsCardProps) => {
+  const [instances, setInstances] = useState(settings.instances.join(", "));
+  const [homeInstance, setHomeInstance] = useState(settings.home_instance);
+  const [embedFrontend, setEmbedFrontend] = useState(settings.embed_frontend);
+
+  // Keep the inputs in sync with updates coming from the backend
+  useEffect(() => {
+    setInstances(settings.instances.join(", "));
+    setHomeInstance(settings.home_instance);
+    setEmbedFrontend(settings.embed_frontend);
+  }, [settings]);
+
+  const saveSettings = async () => {
+    try {
+      await invoke("update_fediverse_settings", {
+        settings: {
+          instances: instances
+            .split(/[\s,]+/)
+            .map((instance) => instance.trim())
+            .filter((instance) => instance.length > 0),
+          home_instance: homeInstance.trim(),
+          embed_frontend: embedFrontend.trim(),
+        },
+      });
+    } catch (error) {
+      console.error("Failed to update fediverse settings:", error);
+    }
+  };
+
+  return (
+    <Card className="gap-2">
+      <CardHeader>
+        <CardTitle className="border-b-1 pb-4">Instances</CardTitle>
+      </CardHeader>
+      <CardContent className="space-y-4">
+        <div className="space-y-2">
+          <Label htmlFor="input-fediverse-instances">
+            Instances to convert links from
+          </Label>
+          <Input
+            id="input-fediverse-instances"
+            placeholder="mastodon.social, fosstodon.org"
+            value={instances}
+            onChange={(event) => setInstances(event.target.value)}
+            onBlur={saveSettings}
+          />
+        </div>
+        <div className="space-y-2">
+          <Label htmlFor="input-fediverse-home">Your home instance</Label>
+          <Input
+            id="input-fediverse-home"
+            placeholder="hachyderm.io"
+            value={homeInstance}
+            onChange={(event) => setHomeInstance(event.target.value)}
+            onBlur={saveSettings}
+          />
+        </div>
+        <div className="space-y-2">
+          <Label htmlFor="input-fediverse-embed">Embed frontend</Label>
+          <Input
+            id="input-fediverse-embed"
+            placeholder="embed.example.com"
+            value={embedFrontend}
+            onChange={(event) => setEmbedFrontend(event.target.value)}
+            onBlur={saveSettings}
+          />
+        </div>
+      </CardContent>
+    </Card>
+  );
+};
+
+export default FediverseSettingsCard;
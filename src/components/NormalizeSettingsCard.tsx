@@ -0,0 +1,55 @@
+import { invoke } from "@tauri-apps/api/core";
+import { NormalizeSettings } from "../types/config";
+import { Card, CardContent, CardHeader, CardTitle } from "./ui/card";
+import { Label } from "./ui/label";
+import { Switch } from "./ui/switch";
+
+interface NormalizeSettingsCardProps {
+  settings: NormalizeSettings;
+}
+
+const NormalizeSettingsCard = ({ settings }: NormalizeSettingsCardProps) => {
+  const setRuleEnabled = async (rule: string, enabled: boolean) => {
+    const disabledRules = settings.disabled_rules.filter((r) => r !== rule);
+    if (!enabled) {
+      disabledRules.push(rule);
+    }
+
+    try {
+      await invoke("update_normalize_settings", {
+        settings: { disabled_rules: disabledRules },
+      });
+    } catch (error) {
+      console.error("Failed to update normalisation settings:", error);
+    }
+  };
+
+  return (
+    <Card className="gap-2">
+      <CardHeader>
+        <CardTitle className="border-b-1 pb-4">AMP & Mobile Links</CardTitle>
+      </CardHeader>
+      <CardContent className="space-y-4">
+        <p className="text-sm text-muted-foreground">
+          Copied AMP and mobile links are turned into desktop links before
+          they are converted. Switch off the rules you don't want.
+        </p>
+        {settings.rules.map((rule) => (
+          <div
+            key={rule}
+            className="flex items-center justify-between space-x-2"
+          >
+            <Label htmlFor={`switch-normalize-${rule}`}>{rule}</Label>
+            <Switch
+              id={`switch-normalize-${rule}`}
+              checked={!settings.disabled_rules.includes(rule)}
+              onCheckedChange={(checked) => setRuleEnabled(rule, checked)}
+            />
+          </div>
+        ))}
+      </CardContent>
+    </Card>
+  );
+};
+
+export default NormalizeSettingsCard;
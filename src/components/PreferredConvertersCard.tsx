@@ -0,0 +1,148 @@
+import { invoke } from "@tauri-apps/api/core";
+import { ArrowDown, ArrowUp, X } from "lucide-react";
+import { ConverterConfig } from "../types/config";
+import { Button } from "./ui/button";
+import { Card, CardContent, CardHeader, CardTitle } from "./ui/card";
+import { Label } from "./ui/label";
+import {
+  Select,
+  SelectContent,
+  SelectItem,
+  SelectTrigger,
+  SelectValue,
+} from "./ui/select";
+import { Switch } from "./ui/switch";
+
+interface PreferredConvertersCardProps {
+  platform: string;
+  config: ConverterConfig;
+}
+
+const PreferredConvertersCard = ({
+  platform,
+  config,
+}: PreferredConvertersCardProps) => {
+  const { preferred, unavailable, active } = config;
+  const remaining = config.converters.filter(
+    (converter) => !preferred.includes(converter)
+  );
+
+  const savePreferred = async (updated: string[]) => {
+    try {
+      await invoke("set_preferred_converters", {
+        platform,
+        converters: updated,
+      });
+    } catch (error) {
+      console.error("Failed to update preferred converters:", error);
+    }
+  };
+
+  const setAvailable = async (converter: string, available: boolean) => {
+    try {
+      await invoke("set_converter_available", {
+        platform,
+        converter,
+        available,
+      });
+    } catch (error) {
+      console.error("Failed to update converter availability:", error);
+    }
+  };
+
+  const move = (index: number, offset: number) => {
+    const updated = [...preferred];
+    [updated[index], updated[index + offset]] = [
+      updated[index + offset],
+      updated[index],
+    ];
+    savePreferred(updated);
+  };
+
+  return (
+    <Card className="gap-2">
+      <CardHeader>
+        <CardTitle className="border-b-1 pb-4">Fallback Converters</CardTitle>
+      </CardHeader>
+      <CardContent className="space-y-4">
+        <p className="text-sm text-muted-foreground">
+          Links are converted with the first available converter in this list.
+          Switch a converter off while its service is down, here or from the
+          tray menu, to fall back to the next one.
+        </p>
+        {preferred.map((converter, index) => (
+          <div
+            key={converter}
+            className="flex items-center justify-between space-x-2"
+          >
+            <Label htmlFor={`switch-${platform}-available-${converter}`}>
+              {index + 1}. {converter}
+              {converter === active && (
+                <span className="ml-1 text-xs text-muted-foreground">
+                  (in use)
+                </span>
+              )}
+            </Label>
+            <div className="flex items-center space-x-1">
+              <Switch
+                id={`switch-${platform}-available-${converter}`}
+                checked={!unavailable.includes(converter)}
+                onCheckedChange={(checked) => setAvailable(converter, checked)}
+              />
+              <Button
+                variant="ghost"
+                size="icon-sm"
+                aria-label={`Move ${converter} up`}
+                disabled={index === 0}
+                onClick={() => move(index, -1)}
+              >
+                <ArrowUp />
+              </Button>
+              <Button
+                variant="ghost"
+                size="icon-sm"
+                aria-label={`Move ${converter} down`}
+                disabled={index === preferred.length - 1}
+                onClick={() => move(index, 1)}
+              >
+                <ArrowDown />
+              </Button>
+              <Button
+                variant="ghost"
+                size="icon-sm"
+                aria-label={`Remove ${converter}`}
+                disabled={preferred.length === 1}
+                onClick={() =>
+                  savePreferred(preferred.filter((c) => c !== converter))
+                }
+              >
+                <X />
+              </Button>
+            </div>
+          </div>
+        ))}
+        {remaining.length > 0 && (
+          <Select
+            value=""
+            onValueChange={(converter) =>
+              savePreferred([...preferred, converter])
+            }
+          >
+            <SelectTrigger id={`select-${platform}-fallback`}>
+              <SelectValue placeholder="Add a fallback converter" />
+            </SelectTrigger>
+            <SelectContent>
+              {remaining.map((converter) => (
+                <SelectItem key={converter} value={converter}>
+                  {converter}
+                </SelectItem>
+              ))}
+            </SelectContent>
+          </Select>
+        )}
+      </CardContent>
+    </Card>
+  );
+};
+
+export default PreferredConvertersCard;
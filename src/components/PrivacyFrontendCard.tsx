@@ -0,0 +1,98 @@
+import { useEffect, useState } from "react";
+import { invoke } from "@tauri-apps/api/core";
+import { InstanceSelection, PrivacyFrontendSettings } from "../types/config";
+import { Card, CardContent, CardHeader, CardTitle } from "./ui/card";
+import { Input } from "./ui/input";
+import { Label } from "./ui/label";
+import {
+  Select,
+  SelectContent,
+  SelectItem,
+  SelectTrigger,
+  SelectValue,
+} from "./ui/select";
+
+interface PrivacyFrontendCardProps {
+  platform: string;
+  settings: PrivacyFrontendSettings;
+}
+
+const SELECTION_LABELS: Record<InstanceSelection, string> = {
+  fixed: "First instance",
+  random: "Random",
+  round_robin: "Round-robin",
+};
+
+const PrivacyFrontendCard = ({ platform, settings }: PrivacyFrontendCardProps) => {
+  const [instances, setInstances] = useState(settings.instances.join(", "));
+
+  // Keep the input in sync with updates coming from the backend
+  useEffect(() => {
+    setInstances(settings.instances.join(", "));
+  }, [settings]);
+
+  const saveSettings = async (selection: InstanceSelection) => {
+    try {
+      await invoke("update_privacy_settings", {
+        platform,
+        settings: {
+          instances: instances
+            .split(/[\s,]+/)
+            .map((instance) => instance.trim())
+            .filter((instance) => instance.length > 0),
+          selection,
+        },
+      });
+    } catch (error) {
+      console.error("Failed to update privacy frontend settings:", error);
+    }
+  };
+
+  return (
+    <Card className="gap-2">
+      <CardHeader>
+        <CardTitle className="border-b-1 pb-4">Privacy Frontend</CardTitle>
+      </CardHeader>
+      <CardContent className="space-y-4">
+        <p className="text-sm text-muted-foreground">
+          Select "{settings.frontend}" as the converter service to open links
+          on one of these instances.
+        </p>
+        <div className="space-y-2">
+          <Label htmlFor={`input-${platform}-instances`}>
+            {settings.frontend} instances
+          </Label>
+          <Input
+            id={`input-${platform}-instances`}
+            placeholder="instance.example.org"
+            value={instances}
+            onChange={(event) => setInstances(event.target.value)}
+            onBlur={() => saveSettings(settings.selection)}
+          />
+        </div>
+        <div className="space-y-2 flex justify-between">
+          <Label htmlFor={`select-${platform}-selection`}>
+            Instance selection
+          </Label>
+          <Select
+            value={settings.selection}
+            onValueChange={(value) => saveSettings(value as InstanceSelection)}
+          >
+            <SelectTrigger id={`select-${platform}-selection`}>
+              <SelectValue />
+            </SelectTrigger>
+            <SelectContent>
+              {Object.entries(SELECTION_LABELS).map(([value, label]) => (
+                <SelectItem key={value} value={value}>
+                  {label}
+                </SelectItem>
+              ))}
+            </SelectContent>
+          </Select>
+        </div>
+      </CardContent>
+    </Card>
+  );
+};
+
+export default PrivacyFrontendCard;
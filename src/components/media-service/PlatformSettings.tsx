@@ -15,6 +15,8 @@ interface PlatformSettingsProps {
   enabled: boolean;
   converters: any[];
   selected: string | null;
+  passthrough?: boolean;
+  active?: string | null; // Converter in use when the selected one is unavailable
   onToggle: (platform: string, enabled: boolean) => void;
   onDropdownSelect: (selected: string) => void;
 }
@@ -25,6 +27,8 @@ const PlatformSettings: React.FC<PlatformSettingsProps> = ({
   enabled,
   converters,
   selected,
+  passthrough = false,
+  active,
   onToggle,
   onDropdownSelect,
 }) => {
@@ -65,6 +69,14 @@ const PlatformSettings: React.FC<PlatformSettingsProps> = ({
             </SelectContent>
           </Select>
         </div>
+
+        {passthrough && (
+          <p className="text-sm text-muted-foreground">
+            "{active ?? selected}" keeps {title} links on {title} itself, so copied
+            links are left unchanged. Pick another converter service to
+            convert them.
+          </p>
+        )}
       </CardContent>
     </Card>
   );
@@ -9,6 +9,11 @@ import HowItWorksSection from "./HowItWorksSection";
 import LoadingSpinner from "../LoadingSpinner";
 import ComingSoonView from "./ComingSoonView";
 import ConversionNotification from "../ConversionNotification";
+import FediverseSettingsCard from "../FediverseSettingsCard";
+import PrivacyFrontendCard from "../PrivacyFrontendCard";
+import ConverterOptionsCard from "../ConverterOptionsCard";
+import CustomConvertersCard from "../CustomConvertersCard";
+import PreferredConvertersCard from "../PreferredConvertersCard";
 
 interface ServiceViewProps {
   platform: string;
@@ -34,6 +39,7 @@ const ServiceView: React.FC<ServiceViewProps> = ({
   } = usePlatform(platform);
 
   const { config } = useConfig();
+  const source = config?.sources.find((s) => s.platform === platform);
 
   // Get dynamic icon for this platform
   const PlatformIcon = useMemo(() => {
@@ -69,10 +75,43 @@ const ServiceView: React.FC<ServiceViewProps> = ({
             enabled={platformData.enabled}
             converters={platformData.converters}
             selected={platformData.selected}
+            passthrough={source?.data.passthrough}
+            active={source?.data.active}
             onToggle={(_platform, enabled) => handleToggle(enabled)}
             onDropdownSelect={handleDropdownSelect}
           />
 
+          {source && (
+            <PreferredConvertersCard platform={platform} config={source.data} />
+          )}
+
+          {source && source.data.active && source.data.supported_options.length > 0 && (
+            <ConverterOptionsCard
+              platform={platform}
+              converter={source.data.active}
+              options={source.data.options}
+              supported={source.data.supported_options}
+            />
+          )}
+
+          {source && (
+            <CustomConvertersCard
+              platform={platform}
+              converters={source.data.custom}
+            />
+          )}
+
+          {platform === "fediverse" && config && (
+            <FediverseSettingsCard settings={config.fediverse} />
+          )}
+
+          {config?.privacy[platform] && (
+            <PrivacyFrontendCard
+              platform={platform}
+              settings={config.privacy[platform]}
+            />
+          )}
+
           <HowItWorksSection steps={howItWorksSteps} />
         </div>
       ) : (
@@ -0,0 +1,37 @@
+import { useEffect, useState } from "react";
+import { invoke } from "@tauri-apps/api/core";
+import { listen } from "@tauri-apps/api/event";
+import { ConfigWarning } from "../types/config";
+
+/**
+ * Hook to fetch the configuration problems found by the backend at startup
+ */
+export function useConfigWarnings(): ConfigWarning[] {
+  const [warnings, setWarnings] = useState<ConfigWarning[]>([]);
+
+  useEffect(() => {
+    invoke<ConfigWarning[]>("get_config_warnings")
+      .then(setWarnings)
+      .catch((error) => console.error("Failed to load config warnings:", error));
+  }, []);
+
+  useEffect(() => {
+    let unlisten: (() => void) | undefined;
+
+    const setupListener = async () => {
+      unlisten = await listen<ConfigWarning[]>("config-warnings", (event) => {
+        setWarnings(event.payload);
+      });
+    };
+
+    setupListener();
+
+    return () => {
+      if (unlisten) {
+        unlisten();
+      }
+    };
+  }, []);
+
+  return warnings;
+}
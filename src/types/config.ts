@@ -3,10 +3,43 @@ export interface PlatformMetadata {
   icon: string; // Simple Icons identifier (e.g., "x", "bluesky", "tiktok")
 }
 
+export interface QueryPolicy {
+  mode: "strip" | "keep";
+  params: string[];
+}
+
+export type ConverterOption =
+  | "direct_media"
+  | "gallery"
+  | "text_only"
+  | "translate";
+
+export interface ConverterOptions {
+  direct_media: boolean;
+  gallery: boolean;
+  text_only: boolean;
+  translate: string | null; // Language code, e.g. "en"
+}
+
+// Self-hosted frontend, listed alongside the built-in converters
+export interface CustomFrontend {
+  name: string;
+  domain: string;
+  template: string; // Path with ids of the original link, e.g. "/{user}/status/{status}"
+}
+
 export interface ConverterConfig {
   enabled: boolean;
   converters: string[];
-  selected: string;
+  selected: string; // First converter of the preference list
+  preferred: string[]; // Converters in order of preference
+  unavailable: string[]; // Converters skipped until they are restored
+  active: string | null; // First preferred converter that is not unavailable
+  query_policy: QueryPolicy;
+  passthrough: boolean; // Enabled, but the active converter leaves vanilla links unchanged
+  options: ConverterOptions;
+  supported_options: ConverterOption[]; // Modes the active converter exposes
+  custom: CustomFrontend[];
 }
 
 export interface PlatformSource {
@@ -15,6 +48,49 @@ export interface PlatformSource {
   data: ConverterConfig;
 }
 
+export interface ClipboardSettings {
+  scan_text: boolean;
+  canonicalize: boolean;
+  retarget: boolean;
+  unwrap_redirects: boolean;
+}
+
+export interface ClearUrlsSettings {
+  enabled: boolean;
+  providers: number; // Rule providers loaded from clearurls.json
+}
+
+export interface FediverseSettings {
+  instances: string[];
+  home_instance: string;
+  embed_frontend: string;
+}
+
+export interface NormalizeSettings {
+  disabled_rules: string[];
+  rules: string[]; // Every rule the normaliser knows, in the order they are applied
+}
+
+export type InstanceSelection = "fixed" | "random" | "round_robin";
+
+export interface PrivacyFrontendSettings {
+  instances: string[];
+  selection: InstanceSelection;
+  frontend: string; // Converter name of the platform's privacy frontend, e.g. "nitter"
+}
+
 export interface AppConfig {
   sources: PlatformSource[];
+  clipboard: ClipboardSettings;
+  clear_urls: ClearUrlsSettings;
+  fediverse: FediverseSettings;
+  normalize: NormalizeSettings;
+  privacy: Record<string, PrivacyFrontendSettings>;
+}
+
+export interface ConfigWarning {
+  platform: string;
+  converter: string | null;
+  fallback: string | null;
+  message: string;
 }
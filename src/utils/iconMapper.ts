@@ -1,6 +1,6 @@
 import { HelpCircle } from "lucide-react";
 import { lazy, ComponentType } from "react";
-import { SiX, SiBluesky, SiTiktok, SiInstagram } from "@icons-pack/react-simple-icons";
+import { SiX, SiBluesky, SiTiktok, SiInstagram, SiReddit, SiThreads, SiMastodon, SiPixiv, SiYoutube } from "@icons-pack/react-simple-icons";
 
 /**
  * Mapping of platform identifiers to their corresponding Simple Icons component names.
@@ -15,6 +15,12 @@ const ICON_MAPPING: Record<string, string> = {
   'bluesky': 'SiBluesky',
   'tiktok': 'SiTiktok',
   'instagram': 'SiInstagram',
+  'reddit': 'SiReddit',
+  'threads': 'SiThreads',
+  'pixiv': 'SiPixiv',
+  'youtube': 'SiYoutube',
+  'fediverse': 'SiMastodon',
+  'mastodon': 'SiMastodon',
 };
 
 /**
@@ -26,6 +32,11 @@ const PRELOADED_ICONS: Record<string, ComponentType<any>> = {
   'SiBluesky': SiBluesky,
   'SiTiktok': SiTiktok,
   'SiInstagram': SiInstagram,
+  'SiReddit': SiReddit,
+  'SiThreads': SiThreads,
+  'SiPixiv': SiPixiv,
+  'SiYoutube': SiYoutube,
+  'SiMastodon': SiMastodon,
 };
 
 /**
@@ -50,7 +61,7 @@ const PRELOADED_ICONS: Record<string, ComponentType<any>> = {
  * ```
  * 
  * @remarks
- * Preloaded icons (Twitter, Bluesky, TikTok, Instagram) render instantly without lazy loading.
+ * Preloaded icons (Twitter, Bluesky, TikTok, Instagram, Reddit, Threads, Pixiv, YouTube, Mastodon) render instantly without lazy loading.
  * Other icons still require a React Suspense boundary for lazy loading.
  * If the icon is not found, it falls back to the HelpCircle icon from lucide-react.
  */
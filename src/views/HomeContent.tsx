@@ -3,6 +3,10 @@ import { ViewType } from "../components/ActiveViewContent";
 import { useConfig } from "../hooks/useConfig";
 import { getSimpleIcon } from "../utils/iconMapper";
 import { HelpCircle, CircleAlert } from "lucide-react";
+import ClearUrlsSettingsCard from "../components/ClearUrlsSettingsCard";
+import ClipboardSettingsCard from "../components/ClipboardSettingsCard";
+import NormalizeSettingsCard from "../components/NormalizeSettingsCard";
+import ConfigWarningsCard from "../components/ConfigWarningsCard";
 import {
   Card,
   CardContent,
@@ -66,6 +70,12 @@ const HomeContent = ({ setActiveView }: HomeContentProps) => {
         </Card>
       </div>
 
+      <ConfigWarningsCard />
+
+      <ClipboardSettingsCard settings={config.clipboard} />
+      <ClearUrlsSettingsCard settings={config.clear_urls} />
+      <NormalizeSettingsCard settings={config.normalize} />
+
       <Card className="gap-4 pt-4">
         <CardHeader className="gap-0">
           <CardTitle className="text-xl">Getting Started</CardTitle>